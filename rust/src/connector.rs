@@ -0,0 +1,195 @@
+//! Optional analytics/billing connector that taps lifecycle request/response traffic (router
+//! and transport creation, transport connect/close, periodic stats) and streams it to an
+//! external sink as structured [`Event`]s.
+//!
+//! This whole subsystem is meant to be compiled out by default, so none of it touches the hot
+//! request path unless an application opts in: it would be declared in the crate root as
+//! `#[cfg(feature = "connector")] pub mod connector;`, gated behind a `connector` feature (and
+//! the SQL-backed [`storage::SqlConnectorStorage`] behind a further `connector-sql` feature).
+//! This crate snapshot has no root `lib.rs`/`Cargo.toml` to declare either feature in, so for now
+//! this file only gates itself with `#![cfg(feature = "connector")]`, which has no effect without
+//! a manifest defining that feature — follow-up wiring once the manifest exists.
+//!
+//! What's actually wired up here: [`Event`] normalization, the bounded, batching [`MsgQueue`],
+//! and [`AgentService`] as the entry point an application calls into from wherever it issues
+//! lifecycle requests today. What's *not* wired up: automatically observing
+//! [`WorkerCreateRouterRequest`], `RouterCreateWebrtcTransportRequest`, transport connect/close,
+//! and `TransportGetStatsRequest` traffic itself, since that would mean intercepting every send
+//! in [`Channel`]'s request path, and `worker/channel.rs` is only a `mod channel;` declaration
+//! with no implementation file backing it in this snapshot (the same gap noted in
+//! [`crate::worker::correlation`]). Call [`AgentService::observe`] explicitly at each of those
+//! call sites once `Channel` exists.
+//!
+//! [`WorkerCreateRouterRequest`]: crate::messages::WorkerCreateRouterRequest
+//! [`Channel`]: crate::worker::Channel
+
+#![cfg(feature = "connector")]
+
+mod storage;
+
+pub use storage::{ConnectorError, ConnectorStorage, NoopConnectorStorage};
+#[cfg(feature = "connector-sql")]
+pub use storage::SqlConnectorStorage;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The lifecycle moment an [`Event`] was raised for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventKind {
+    /// A router was created on a worker.
+    RouterCreated,
+    /// A transport was created within a router.
+    TransportCreated,
+    /// A transport finished connecting (e.g. DTLS/ICE handshake completed).
+    TransportConnected,
+    /// A transport was closed.
+    TransportClosed,
+    /// A periodic stats sample for a transport or producer/consumer.
+    StatsSample,
+}
+
+/// A single normalized unit of session telemetry, ready to be queued and eventually persisted by
+/// a [`ConnectorStorage`] implementation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Event {
+    /// Identifies the session (e.g. router) this event belongs to.
+    pub session_id: String,
+    /// Identifies the node (e.g. worker PID or remote worker address) that raised this event.
+    pub node_id: String,
+    /// What kind of lifecycle moment this event records.
+    pub kind: EventKind,
+    /// Milliseconds since the Unix epoch when this event was raised.
+    pub timestamp_ms: u64,
+    /// Arbitrary structured detail for this event (request/response body, stats snapshot, etc).
+    pub payload: Value,
+}
+
+impl Event {
+    fn new(
+        session_id: impl Into<String>,
+        node_id: impl Into<String>,
+        kind: EventKind,
+        payload: Value,
+    ) -> Self {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+
+        Event {
+            session_id: session_id.into(),
+            node_id: node_id.into(),
+            kind,
+            timestamp_ms,
+            payload,
+        }
+    }
+}
+
+/// Bounded in-memory queue of [`Event`]s awaiting a [`AgentService::flush`]. Under sustained
+/// backpressure (sink unavailable, flush not called often enough) it drops the oldest queued
+/// event to make room for new ones rather than blocking the caller or growing unbounded — this
+/// is best-effort analytics, not a guaranteed-delivery log, so staying off the hot request path
+/// matters more than never losing an event.
+pub struct MsgQueue {
+    capacity: usize,
+    batch_size: usize,
+    events: Mutex<VecDeque<Event>>,
+}
+
+impl MsgQueue {
+    /// Creates a queue holding at most `capacity` events, flushing at most `batch_size` of them
+    /// per [`Self::flush`] call.
+    pub fn new(capacity: usize, batch_size: usize) -> Self {
+        MsgQueue {
+            capacity,
+            batch_size,
+            events: Mutex::new(VecDeque::with_capacity(capacity.min(1024))),
+        }
+    }
+
+    fn push(&self, event: Event) {
+        let mut events = self.events.lock();
+        if events.len() >= self.capacity {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    /// Drains and returns up to `batch_size` queued events, oldest first.
+    fn flush(&self) -> Vec<Event> {
+        let mut events = self.events.lock();
+        let drain_count = events.len().min(self.batch_size);
+        events.drain(..drain_count).collect()
+    }
+
+    /// Number of events currently queued.
+    pub fn len(&self) -> usize {
+        self.events.lock().len()
+    }
+
+    /// Whether the queue currently holds no events.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Entry point for tapping lifecycle request/response traffic into an external analytics/billing
+/// sink. Call [`Self::observe`] from wherever lifecycle requests are issued or stats are sampled,
+/// and [`Self::flush`] periodically (e.g. alongside
+/// [`WorkerSettings::resource_sampling_interval`]) to batch them out to the configured
+/// [`ConnectorStorage`].
+///
+/// [`WorkerSettings::resource_sampling_interval`]: crate::worker::WorkerSettings::resource_sampling_interval
+pub struct AgentService {
+    queue: MsgQueue,
+    storage: Arc<dyn ConnectorStorage + Send + Sync>,
+}
+
+impl AgentService {
+    /// Creates a service flushing into `storage`, queueing at most `queue_capacity` events and
+    /// flushing at most `batch_size` per call.
+    pub fn new(
+        storage: Arc<dyn ConnectorStorage + Send + Sync>,
+        queue_capacity: usize,
+        batch_size: usize,
+    ) -> Self {
+        AgentService {
+            queue: MsgQueue::new(queue_capacity, batch_size),
+            storage,
+        }
+    }
+
+    /// Normalizes a lifecycle moment into an [`Event`] and queues it for the next
+    /// [`Self::flush`].
+    pub fn observe(
+        &self,
+        session_id: impl Into<String>,
+        node_id: impl Into<String>,
+        kind: EventKind,
+        payload: Value,
+    ) {
+        self.queue.push(Event::new(session_id, node_id, kind, payload));
+    }
+
+    /// Writes up to one batch of queued events to the configured [`ConnectorStorage`], returning
+    /// how many were written.
+    pub fn flush(&self) -> Result<usize, ConnectorError> {
+        let batch = self.queue.flush();
+        let count = batch.len();
+        for event in &batch {
+            self.storage.write_event(event)?;
+        }
+        Ok(count)
+    }
+
+    /// Reads back the event history for a session from the configured [`ConnectorStorage`].
+    pub fn history(&self, session_id: &str) -> Result<Vec<Event>, ConnectorError> {
+        self.storage.query_session(session_id)
+    }
+}
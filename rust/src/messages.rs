@@ -1,10 +1,15 @@
 use crate::data_structures::*;
+use crate::producer::ProducerId;
+use crate::router::data_consumer::DataConsumerStat;
+use crate::router::data_producer::DataProducerStat;
 use crate::router::RouterDumpResponse;
+use crate::rtp_parameters::MediaKind;
 use crate::worker::{WorkerDumpResponse, WorkerResourceUsage, WorkerUpdateSettings};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use thiserror::Error;
 
 pub(crate) trait Request: Debug + Serialize {
     type Response: DeserializeOwned;
@@ -12,13 +17,90 @@ pub(crate) trait Request: Debug + Serialize {
     fn as_method(&self) -> &'static str;
 }
 
+/// Error returned by a [`Codec`] when it fails to encode a request or decode a response.
+#[derive(Debug, Clone, Error, Eq, PartialEq)]
+pub(crate) enum CodecError {
+    /// Failed to encode request.
+    #[error("failed to encode request: {0}")]
+    Encode(String),
+    /// Failed to decode response.
+    #[error("failed to decode response: {0}")]
+    Decode(String),
+}
+
+/// Wire-format abstraction for a worker channel request/response round trip. Lets a worker
+/// handle pick its wire format once at startup instead of every round trip being hard-coded to
+/// JSON; every message still round-trips through the same typed [`Request`]/`Request::Response`
+/// pair regardless of which [`Codec`] is picked, only the bytes crossing the channel differ.
+pub(crate) trait Codec {
+    /// Encodes `request` (tagged with the channel's per-request `id`, used to match the worker's
+    /// response back to the caller awaiting it) into the bytes sent over the worker channel.
+    fn encode<R: Request>(request: &R, id: u32) -> Result<Vec<u8>, CodecError>;
+
+    /// Decodes a worker response payload back into `R::Response`.
+    fn decode<R: Request>(bytes: &[u8]) -> Result<R::Response, CodecError>;
+}
+
+/// The JSON wire format every worker channel round trip has always used: a JSON envelope
+/// carrying the request `id`/method alongside the request's own fields, and a bare JSON response
+/// body.
+pub(crate) struct JsonCodec;
+
+impl Codec for JsonCodec {
+    fn encode<R: Request>(request: &R, id: u32) -> Result<Vec<u8>, CodecError> {
+        #[derive(Serialize)]
+        struct Envelope<'a, R> {
+            id: u32,
+            method: &'static str,
+            #[serde(flatten)]
+            request: &'a R,
+        }
+
+        serde_json::to_vec(&Envelope {
+            id,
+            method: request.as_method(),
+            request,
+        })
+        .map_err(|error| CodecError::Encode(error.to_string()))
+    }
+
+    fn decode<R: Request>(bytes: &[u8]) -> Result<R::Response, CodecError> {
+        serde_json::from_slice(bytes).map_err(|error| CodecError::Decode(error.to_string()))
+    }
+}
+
+/// A prost-backed binary wire format mirroring [`JsonCodec`]'s envelope, dropping JSON
+/// parse/stringify cost on both the Rust and C++ sides for high-throughput deployments.
+///
+/// Not implemented in this crate yet: it needs prost-generated message types that structurally
+/// mirror every `request_response!`-generated `*Request`/`*Response` pair (with field tags
+/// emitted by the macro itself, so the two representations can't drift apart), plus a `build.rs`
+/// step to compile the corresponding `.proto` schema — neither of which this crate currently has.
+/// [`Codec`] exists so call sites can already be written against the trait and switched over to
+/// this once that build pipeline lands, without any caller-visible change.
+pub(crate) struct BinaryCodec;
+
+impl Codec for BinaryCodec {
+    fn encode<R: Request>(_request: &R, _id: u32) -> Result<Vec<u8>, CodecError> {
+        Err(CodecError::Encode(
+            "BinaryCodec is not implemented yet (no prost build pipeline in this crate)".into(),
+        ))
+    }
+
+    fn decode<R: Request>(_bytes: &[u8]) -> Result<R::Response, CodecError> {
+        Err(CodecError::Decode(
+            "BinaryCodec is not implemented yet (no prost build pipeline in this crate)".into(),
+        ))
+    }
+}
+
 macro_rules! request_response {
     (
         $method: literal,
         $request_struct_name: ident { $( $field_name: ident: $field_type: ty, )* },
         $existing_response_type: ty $(,)?
     ) => {
-        #[derive(Debug, Serialize)]
+        #[derive(Debug, Clone, Serialize)]
         pub(crate) struct $request_struct_name {
             $( pub(crate) $field_name: $field_type, )*
         }
@@ -43,7 +125,7 @@ macro_rules! request_response {
         $request_struct_name: ident { $( $field_name: ident: $field_type: ty, )* },
         $response_struct_name: ident $response_struct_impl: tt,
     ) => {
-        #[derive(Debug, Serialize)]
+        #[derive(Debug, Clone, Serialize)]
         pub(crate) struct $request_struct_name {
             $( pub(crate) $field_name: $field_type, )*
         }
@@ -103,6 +185,8 @@ request_response!(
     },
 );
 
+request_response!("worker.close", WorkerCloseRequest {},);
+
 request_response!(
     "worker.createRouter",
     WorkerCreateRouterRequest {
@@ -226,7 +310,6 @@ request_response!(
     },
 );
 
-// TODO: Detail remaining methods, I got bored for now
 request_response!(
     "transport.restartIce",
     TransportRestartIceRequest {
@@ -237,6 +320,142 @@ request_response!(
     },
 );
 
+request_response!(
+    "dataProducer.pause",
+    DataProducerPauseRequest {
+        internal: DataProducerInternal,
+    },
+);
+
+request_response!(
+    "dataProducer.resume",
+    DataProducerResumeRequest {
+        internal: DataProducerInternal,
+    },
+);
+
+request_response!(
+    "dataProducer.getBufferedAmount",
+    DataProducerGetBufferedAmountRequest {
+        internal: DataProducerInternal,
+    },
+    DataProducerGetBufferedAmountResponse {
+        buffered_amount: u32,
+    },
+);
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DataProducerSetBufferedAmountLowThresholdData {
+    pub(crate) threshold: u32,
+}
+
+request_response!(
+    "dataProducer.setBufferedAmountLowThreshold",
+    DataProducerSetBufferedAmountLowThresholdRequest {
+        internal: DataProducerInternal,
+        data: DataProducerSetBufferedAmountLowThresholdData,
+    },
+);
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DataConsumerSetPriorityData {
+    pub(crate) priority: u8,
+}
+
+request_response!(
+    "dataConsumer.setPriority",
+    DataConsumerSetPriorityRequest {
+        internal: DataConsumerInternal,
+        data: DataConsumerSetPriorityData,
+    },
+);
+
+request_response!(
+    "dataConsumer.pause",
+    DataConsumerPauseRequest {
+        internal: DataConsumerInternal,
+    },
+);
+
+request_response!(
+    "dataConsumer.resume",
+    DataConsumerResumeRequest {
+        internal: DataConsumerInternal,
+    },
+);
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DataConsumerGrantCreditsData {
+    pub(crate) credits: u32,
+}
+
+request_response!(
+    "dataConsumer.grantCredits",
+    DataConsumerGrantCreditsRequest {
+        internal: DataConsumerInternal,
+        data: DataConsumerGrantCreditsData,
+    },
+);
+
+/// Describes a single message within a batch sent via
+/// [`DirectDataConsumer::send_batch`](crate::data_consumer::DirectDataConsumer::send_batch),
+/// locating it within the concatenated payload buffer.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DataConsumerSendBatchDescriptor {
+    /// PPID of this message.
+    pub(crate) ppid: u32,
+    /// Offset of this message's payload within the concatenated buffer.
+    pub(crate) offset: usize,
+    /// Length in bytes of this message's payload within the concatenated buffer.
+    pub(crate) len: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DataConsumerSendBatchRequestData {
+    pub(crate) descriptors: Vec<DataConsumerSendBatchDescriptor>,
+}
+
+request_response!(
+    "dataConsumer.sendBatch",
+    DataConsumerSendBatchRequest {
+        internal: DataConsumerInternal,
+        data: DataConsumerSendBatchRequestData,
+    },
+    DataConsumerSendBatchResponse {
+        /// Indices (into the request's `descriptors`) of messages the worker failed to
+        /// deliver, e.g. because the SCTP send buffer became full partway through the batch.
+        failed_indices: Vec<usize>,
+    },
+);
+
+/// Describes a single message within a batch sent via
+/// [`DirectDataProducer::send_many`](crate::data_producer::DirectDataProducer::send_many), whose
+/// payloads are concatenated into a single notification.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DataProducerSendManyData {
+    /// PPID of each individual message, in order.
+    pub(crate) ppids: Vec<u32>,
+    /// Length in bytes of each individual message's payload within the concatenated buffer, in
+    /// the same order as `ppids`.
+    pub(crate) lens: Vec<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct DataProducerSendManyNotification {
+    pub(crate) internal: DataProducerInternal,
+    pub(crate) data: DataProducerSendManyData,
+}
+
+// The four RPCs below create a Producer/Consumer/DataProducer/DataConsumer on top of an existing
+// transport; unlike the entity-level methods finished below, they need a transport-side
+// `Transport` module that doesn't exist in this crate snapshot yet, so they stay stubbed out
+// here rather than guessed at.
 // request_response!(
 //     TransportProduceRequest,
 //     "transport.produce",
@@ -276,293 +495,354 @@ request_response!(
 //         // TODO
 //     },
 // );
-//
-// request_response!(
-//     TransportEnableTraceEventRequest,
-//     "transport.enableTraceEvent",
-//     ;,
-//     TransportEnableTraceEventResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ProducerCloseRequest,
-//     "producer.close",
-//     ;,
-//     ProducerCloseResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ProducerDumpRequest,
-//     "producer.dump",
-//     ;,
-//     ProducerDumpResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ProducerGetStatsRequest,
-//     "producer.getStats",
-//     ;,
-//     ProducerGetStatsResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ProducerPauseRequest,
-//     "producer.pause",
-//     ;,
-//     ProducerPauseResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ProducerResumeRequest,
-//     "producer.resume",
-//     ;,
-//     ProducerResumeResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ProducerEnableTraceEventRequest,
-//     "producer.enableTraceEvent",
-//     ;,
-//     ProducerEnableTraceEventResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ConsumerCloseRequest,
-//     "consumer.close",
-//     ;,
-//     ConsumerCloseResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ConsumerDumpRequest,
-//     "consumer.dump",
-//     ;,
-//     ConsumerDumpResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ConsumerGetStatsRequest,
-//     "consumer.getStats",
-//     ;,
-//     ConsumerGetStatsResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ConsumerPauseRequest,
-//     "consumer.pause",
-//     ;,
-//     ConsumerPauseResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ConsumerResumeRequest,
-//     "consumer.resume",
-//     ;,
-//     ConsumerResumeResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ConsumerSetPreferredLayersRequest,
-//     "consumer.setPreferredLayers",
-//     ;,
-//     ConsumerSetPreferredLayersResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ConsumerSetPriorityRequest,
-//     "consumer.setPriority",
-//     ;,
-//     ConsumerSetPriorityResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ConsumerRequestKeyFrameRequest,
-//     "consumer.requestKeyFrame",
-//     ;,
-//     ConsumerRequestKeyFrameResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     ConsumerEnableTraceEventRequest,
-//     "consumer.enableTraceEvent",
-//     ;,
-//     ConsumerEnableTraceEventResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     DataProducerCloseRequest,
-//     "dataProducer.close",
-//     ;,
-//     DataProducerCloseResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     DataProducerDumpRequest,
-//     "dataProducer.dump",
-//     ;,
-//     DataProducerDumpResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     DataProducerGetStatsRequest,
-//     "dataProducer.getStats",
-//     ;,
-//     DataProducerGetStatsResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     DataConsumerCloseRequest,
-//     "dataConsumer.close",
-//     ;,
-//     DataConsumerCloseResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     DataConsumerDumpRequest,
-//     "dataConsumer.dump",
-//     ;,
-//     DataConsumerDumpResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     DataConsumerGetStatsRequest,
-//     "dataConsumer.getStats",
-//     ;,
-//     DataConsumerGetStatsResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     DataConsumerGetBufferedAmountRequest,
-//     "dataConsumer.getBufferedAmount",
-//     ;,
-//     DataConsumerGetBufferedAmountResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     DataConsumerSetBufferedAmountLowThresholdRequest,
-//     "dataConsumer.setBufferedAmountLowThreshold",
-//     ;,
-//     DataConsumerSetBufferedAmountLowThresholdResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     RtpObserverCloseRequest,
-//     "rtpObserver.close",
-//     ;,
-//     RtpObserverCloseResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     RtpObserverPauseRequest,
-//     "rtpObserver.pause",
-//     ;,
-//     RtpObserverPauseResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     RtpObserverResumeRequest,
-//     "rtpObserver.resume",
-//     ;,
-//     RtpObserverResumeResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     RtpObserverAddProducerRequest,
-//     "rtpObserver.addProducer",
-//     ;,
-//     RtpObserverAddProducerResponse,
-//     {
-//         // TODO
-//     },
-// );
-//
-// request_response!(
-//     RtpObserverRemoveProducerRequest,
-//     "rtpObserver.removeProducer",
-//     ;,
-//     RtpObserverRemoveProducerResponse,
-//     {
-//         // TODO
-//     },
-// );
+
+/// Selects which RTP-level events a [`Producer`](crate::producer::Producer) or
+/// [`Consumer`](crate::consumer::Consumer) reports back over its `trace` event, matching the
+/// discontinuity/keyframe-oriented events mediasoup's RTP payload handling can emit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TraceEventType {
+    /// An RTP packet was received/sent.
+    Rtp,
+    /// A keyframe was received/sent.
+    Keyframe,
+    /// A NACK (Generic NACK) RTCP feedback packet was received/sent.
+    Nack,
+    /// A PLI (Picture Loss Indication) RTCP feedback packet was received/sent.
+    Pli,
+    /// A FIR (Full Intra Request) RTCP feedback packet was received/sent.
+    Fir,
+    /// A bandwidth estimation change was detected.
+    Bwe,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProducerEnableTraceEventData {
+    pub(crate) types: Vec<TraceEventType>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ConsumerEnableTraceEventData {
+    pub(crate) types: Vec<TraceEventType>,
+}
+
+/// A single RTP stream's stats, as returned within `producer.getStats`'s response — one entry
+/// per encoding (simulcast layer) the producer carries.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProducerStat {
+    /// Media kind this stream carries.
+    pub kind: MediaKind,
+    /// Negotiated codec MIME type, e.g. `"video/VP8"`.
+    pub mime_type: String,
+    /// SSRC of this stream.
+    pub ssrc: u32,
+    /// `rid` of this encoding, if the producer uses simulcast.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rid: Option<String>,
+    /// Packets received so far.
+    pub packet_count: u64,
+    /// Bytes received so far.
+    pub byte_count: u64,
+    /// Current receive bitrate, in bits per second.
+    pub bitrate: u32,
+    /// Packets lost, as reported by the remote sender report.
+    pub packets_lost: u32,
+    /// Fraction of packets lost in the last reporting interval, 0-255 per RFC 3550.
+    pub fraction_lost: u8,
+    /// Packets discarded due to a full receive buffer.
+    pub packets_discarded: u64,
+    /// Retransmitted packets received via RTX.
+    pub packets_retransmitted: u64,
+    /// Packets repaired via FEC.
+    pub packets_repaired: u64,
+    /// NACK RTCP feedback packets sent upstream.
+    pub nack_count: u32,
+    /// PLI RTCP feedback packets sent upstream.
+    pub pli_count: u32,
+    /// FIR RTCP feedback packets sent upstream.
+    pub fir_count: u32,
+    /// mediasoup's own 0-10 health score for this stream.
+    pub score: u8,
+    /// Round-trip time to the sender, in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub round_trip_time: Option<u32>,
+}
+
+/// A single RTP stream's stats, as returned within `consumer.getStats`'s response.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsumerStat {
+    /// Media kind this stream carries.
+    pub kind: MediaKind,
+    /// Negotiated codec MIME type, e.g. `"video/VP8"`.
+    pub mime_type: String,
+    /// SSRC of this stream.
+    pub ssrc: u32,
+    /// Packets sent so far.
+    pub packet_count: u64,
+    /// Bytes sent so far.
+    pub byte_count: u64,
+    /// Current send bitrate, in bits per second.
+    pub bitrate: u32,
+    /// Packets lost, as reported by the remote receiver report.
+    pub packets_lost: u32,
+    /// Fraction of packets lost in the last reporting interval, 0-255 per RFC 3550.
+    pub fraction_lost: u8,
+    /// Retransmitted packets sent via RTX.
+    pub packets_retransmitted: u64,
+    /// NACK RTCP feedback packets received from the remote endpoint.
+    pub nack_count: u32,
+    /// PLI RTCP feedback packets received from the remote endpoint.
+    pub pli_count: u32,
+    /// FIR RTCP feedback packets received from the remote endpoint.
+    pub fir_count: u32,
+    /// mediasoup's own 0-10 health score for this stream.
+    pub score: u8,
+    /// Jitter buffer delay, in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jitter: Option<u32>,
+    /// Round-trip time to the remote endpoint, in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub round_trip_time: Option<u32>,
+}
+
+/// Selects which spatial/temporal layer a simulcast/SVC consumer should forward, set via
+/// `consumer.setPreferredLayers`. Either field may be left unset to let mediasoup choose, or to
+/// clear a previously set preference.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreferredLayers {
+    /// Preferred spatial layer, for simulcast/SVC video.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spatial_layer: Option<u8>,
+    /// Preferred temporal layer, for simulcast/SVC video.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporal_layer: Option<u8>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ConsumerSetPriorityData {
+    pub(crate) priority: u8,
+}
+
+request_response!(
+    "producer.close",
+    ProducerCloseRequest {
+        internal: ProducerInternal,
+    },
+);
+
+request_response!(
+    "producer.dump",
+    ProducerDumpRequest {
+        internal: ProducerInternal,
+    },
+    ProducerDumpResponse {
+        // TODO
+    },
+);
+
+request_response!(
+    "producer.getStats",
+    ProducerGetStatsRequest {
+        internal: ProducerInternal,
+    },
+    ProducerGetStatsResponse {
+        stats: Vec<ProducerStat>,
+    },
+);
+
+request_response!(
+    "producer.pause",
+    ProducerPauseRequest {
+        internal: ProducerInternal,
+    },
+);
+
+request_response!(
+    "producer.resume",
+    ProducerResumeRequest {
+        internal: ProducerInternal,
+    },
+);
+
+request_response!(
+    "producer.enableTraceEvent",
+    ProducerEnableTraceEventRequest {
+        internal: ProducerInternal,
+        data: ProducerEnableTraceEventData,
+    },
+);
+
+request_response!(
+    "consumer.close",
+    ConsumerCloseRequest {
+        internal: ConsumerInternal,
+    },
+);
+
+request_response!(
+    "consumer.dump",
+    ConsumerDumpRequest {
+        internal: ConsumerInternal,
+    },
+    ConsumerDumpResponse {
+        // TODO
+    },
+);
+
+request_response!(
+    "consumer.getStats",
+    ConsumerGetStatsRequest {
+        internal: ConsumerInternal,
+    },
+    ConsumerGetStatsResponse {
+        stats: Vec<ConsumerStat>,
+    },
+);
+
+request_response!(
+    "consumer.pause",
+    ConsumerPauseRequest {
+        internal: ConsumerInternal,
+    },
+);
+
+request_response!(
+    "consumer.resume",
+    ConsumerResumeRequest {
+        internal: ConsumerInternal,
+    },
+);
+
+request_response!(
+    "consumer.setPreferredLayers",
+    ConsumerSetPreferredLayersRequest {
+        internal: ConsumerInternal,
+        data: PreferredLayers,
+    },
+);
+
+request_response!(
+    "consumer.setPriority",
+    ConsumerSetPriorityRequest {
+        internal: ConsumerInternal,
+        data: ConsumerSetPriorityData,
+    },
+);
+
+request_response!(
+    "consumer.requestKeyFrame",
+    ConsumerRequestKeyFrameRequest {
+        internal: ConsumerInternal,
+    },
+);
+
+request_response!(
+    "consumer.enableTraceEvent",
+    ConsumerEnableTraceEventRequest {
+        internal: ConsumerInternal,
+        data: ConsumerEnableTraceEventData,
+    },
+);
+
+request_response!(
+    "dataProducer.close",
+    DataProducerCloseRequest {
+        internal: DataProducerInternal,
+    },
+);
+
+request_response!(
+    "dataProducer.dump",
+    DataProducerDumpRequest {
+        internal: DataProducerInternal,
+    },
+    DataProducerDumpResponse {
+        // TODO
+    },
+);
+
+request_response!(
+    "dataProducer.getStats",
+    DataProducerGetStatsRequest {
+        internal: DataProducerInternal,
+    },
+    DataProducerGetStatsResponse {
+        stats: Vec<DataProducerStat>,
+    },
+);
+
+request_response!(
+    "dataConsumer.close",
+    DataConsumerCloseRequest {
+        internal: DataConsumerInternal,
+    },
+);
+
+request_response!(
+    "dataConsumer.dump",
+    DataConsumerDumpRequest {
+        internal: DataConsumerInternal,
+    },
+    DataConsumerDumpResponse {
+        // TODO
+    },
+);
+
+request_response!(
+    "dataConsumer.getStats",
+    DataConsumerGetStatsRequest {
+        internal: DataConsumerInternal,
+    },
+    DataConsumerGetStatsResponse {
+        stats: Vec<DataConsumerStat>,
+    },
+);
+
+request_response!(
+    "rtpObserver.close",
+    RtpObserverCloseRequest {
+        internal: RtpObserverInternal,
+    },
+);
+
+request_response!(
+    "rtpObserver.pause",
+    RtpObserverPauseRequest {
+        internal: RtpObserverInternal,
+    },
+);
+
+request_response!(
+    "rtpObserver.resume",
+    RtpObserverResumeRequest {
+        internal: RtpObserverInternal,
+    },
+);
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RtpObserverAddRemoveProducerData {
+    pub(crate) producer_id: ProducerId,
+}
+
+request_response!(
+    "rtpObserver.addProducer",
+    RtpObserverAddProducerRequest {
+        internal: RtpObserverInternal,
+        data: RtpObserverAddRemoveProducerData,
+    },
+);
+
+request_response!(
+    "rtpObserver.removeProducer",
+    RtpObserverRemoveProducerRequest {
+        internal: RtpObserverInternal,
+        data: RtpObserverAddRemoveProducerData,
+    },
+);
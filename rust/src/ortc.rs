@@ -0,0 +1,510 @@
+//! ORTC-style matching between a router's supported [`RtpCapabilities`] and an endpoint's
+//! declared ones, producing the reduced [`RtpParameters`] a [`Consumer`](crate::consumer::Consumer)
+//! should use. Not yet declared as `mod ortc;` anywhere — see `CONTRIBUTING.md` for why.
+//!
+//! Codec matching follows [`RtpCodecCapability`]'s doc comment: two codecs of the same MIME type
+//! are the same codec only if their clock rate, channel count (audio), and the parameters the
+//! doc comment calls out as critical (`packetization-mode`/`profile-level-id` for H264,
+//! `profile-id` for VP9) all agree. A matched codec's RTX companion is found via its `apt`
+//! parameter, matching [`RtpCodecParameters`]'s doc comment for how RTX pairing works on the
+//! wire. Header extension matching follows [`RtpHeaderExtension`]'s doc comment: the `direction`
+//! field is only meaningful on the router's side and is ignored if present in an endpoint's
+//! capabilities.
+
+use crate::rtp_parameters::{
+    MediaKind, RtcpFeedback, RtpCapabilities, RtpCodecCapability, RtpCodecParameters,
+    RtpHeaderExtensionDirection, RtpHeaderExtensionParameters, RtpParameters,
+};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use thiserror::Error;
+
+/// Lowest payload type mediasoup will assign to a dynamically negotiated codec.
+const DYNAMIC_PAYLOAD_TYPE_MIN: u8 = 96;
+/// Highest payload type mediasoup will assign to a dynamically negotiated codec, per
+/// [`RtpCodecCapability`]'s doc comment.
+const DYNAMIC_PAYLOAD_TYPE_MAX: u8 = 127;
+
+/// Error returned when a router's and a remote endpoint's [`RtpCapabilities`] can't be matched
+/// into usable [`RtpParameters`].
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum OrtcError {
+    /// None of the remote's codecs are supported by the router.
+    #[error("no codec in the remote capabilities is supported by the router")]
+    NoMatchingCodec,
+    /// Every payload type in the dynamic range is already taken by an earlier matched codec.
+    #[error(
+        "ran out of dynamic payload types in the \
+         {DYNAMIC_PAYLOAD_TYPE_MIN}-{DYNAMIC_PAYLOAD_TYPE_MAX} range while matching codecs"
+    )]
+    NoMorePayloadTypes,
+}
+
+/// One matched media codec, plus its RTX codec if both sides support RTX for it.
+#[derive(Debug, Clone)]
+pub struct MatchedCodec {
+    /// The negotiated media codec, with payload type and parameters resolved for this match.
+    pub media_codec: RtpCodecParameters,
+    /// The negotiated RTX codec covering `media_codec`, if RTX was offered and supported on both
+    /// sides.
+    pub rtx_codec: Option<RtpCodecParameters>,
+}
+
+/// Codec-specific parameters [`RtpCodecCapability`]'s doc comment calls out as critical for
+/// matching, beyond clock rate/channels, keyed by lowercased MIME type.
+fn matching_parameter_keys(mime_type: &str) -> &'static [&'static str] {
+    match mime_type.to_ascii_lowercase().as_str() {
+        "video/h264" | "video/h264-svc" => &["packetization-mode", "profile-level-id"],
+        "video/vp9" => &["profile-id"],
+        _ => &[],
+    }
+}
+
+fn is_rtx_mime_type(mime_type: &str) -> bool {
+    mime_type.eq_ignore_ascii_case("audio/rtx") || mime_type.eq_ignore_ascii_case("video/rtx")
+}
+
+fn codecs_match(router_codec: &RtpCodecCapability, remote_codec: &RtpCodecCapability) -> bool {
+    if !router_codec.mime_type.eq_ignore_ascii_case(&remote_codec.mime_type) {
+        return false;
+    }
+    if router_codec.clock_rate != remote_codec.clock_rate {
+        return false;
+    }
+    if router_codec.kind == MediaKind::Audio
+        && router_codec.channels.unwrap_or(1) != remote_codec.channels.unwrap_or(1)
+    {
+        return false;
+    }
+
+    matching_parameter_keys(&router_codec.mime_type)
+        .iter()
+        .all(|key| router_codec.parameters.get(*key) == remote_codec.parameters.get(*key))
+}
+
+/// Keeps only the [`RtcpFeedback`] entries both `router` and `remote` declared for a matched
+/// codec.
+fn intersect_rtcp_feedback(
+    router: Option<&[RtcpFeedback]>,
+    remote: Option<&[RtcpFeedback]>,
+) -> Vec<RtcpFeedback> {
+    let remote = remote.unwrap_or_default();
+    router
+        .unwrap_or_default()
+        .iter()
+        .copied()
+        .filter(|feedback| remote.contains(feedback))
+        .collect()
+}
+
+/// Hands out payload types for matched codecs: a remote's preferred payload type if it's free and
+/// in the dynamic range, otherwise the next free dynamic payload type.
+struct PayloadTypeAllocator {
+    used: Vec<u8>,
+    next_dynamic: u8,
+}
+
+impl PayloadTypeAllocator {
+    fn new() -> Self {
+        PayloadTypeAllocator {
+            used: Vec::new(),
+            next_dynamic: DYNAMIC_PAYLOAD_TYPE_MIN,
+        }
+    }
+
+    fn allocate(&mut self, preferred: Option<u32>) -> Result<u8, OrtcError> {
+        if let Some(preferred) = preferred.and_then(|pt| u8::try_from(pt).ok()) {
+            if (DYNAMIC_PAYLOAD_TYPE_MIN..=DYNAMIC_PAYLOAD_TYPE_MAX).contains(&preferred)
+                && !self.used.contains(&preferred)
+            {
+                self.used.push(preferred);
+                return Ok(preferred);
+            }
+        }
+
+        while self.used.contains(&self.next_dynamic) {
+            self.next_dynamic = self
+                .next_dynamic
+                .checked_add(1)
+                .ok_or(OrtcError::NoMorePayloadTypes)?;
+        }
+        if self.next_dynamic > DYNAMIC_PAYLOAD_TYPE_MAX {
+            return Err(OrtcError::NoMorePayloadTypes);
+        }
+
+        let payload_type = self.next_dynamic;
+        self.used.push(payload_type);
+        Ok(payload_type)
+    }
+}
+
+/// Finds `remote_media_codec`'s RTX companion (if any) in `remote_codecs`, matches it against a
+/// router RTX codec of the same clock rate, and rewrites its `apt` parameter to point at
+/// `media_payload_type`, the payload type the paired media codec was just assigned.
+fn match_rtx_codec(
+    router_codecs: &[RtpCodecCapability],
+    remote_codecs: &[RtpCodecCapability],
+    remote_media_codec: &RtpCodecCapability,
+    media_payload_type: u8,
+    allocator: &mut PayloadTypeAllocator,
+) -> Result<Option<RtpCodecParameters>, OrtcError> {
+    let remote_preferred_pt = match remote_media_codec.preferred_payload_type {
+        Some(pt) => pt,
+        None => return Ok(None),
+    };
+
+    let remote_rtx = remote_codecs.iter().find(|codec| {
+        is_rtx_mime_type(&codec.mime_type)
+            && codec
+                .parameters
+                .get("apt")
+                .and_then(|apt| apt.parse::<u32>().ok())
+                == Some(remote_preferred_pt)
+    });
+    let remote_rtx = match remote_rtx {
+        Some(codec) => codec,
+        None => return Ok(None),
+    };
+
+    let router_rtx = router_codecs.iter().find(|codec| {
+        is_rtx_mime_type(&codec.mime_type) && codec.clock_rate == remote_rtx.clock_rate
+    });
+    let router_rtx = match router_rtx {
+        Some(codec) => codec,
+        None => return Ok(None),
+    };
+
+    let rtx_payload_type = allocator.allocate(remote_rtx.preferred_payload_type)?;
+
+    let mut parameters = HashMap::new();
+    parameters.insert("apt".to_string(), media_payload_type.to_string());
+
+    Ok(Some(RtpCodecParameters {
+        mime_type: router_rtx.mime_type.clone(),
+        payload_type: rtx_payload_type,
+        clock_rate: router_rtx.clock_rate,
+        channels: None,
+        parameters,
+        rtcp_feedback: Some(intersect_rtcp_feedback(
+            router_rtx.rtcp_feedback.as_deref(),
+            remote_rtx.rtcp_feedback.as_deref(),
+        )),
+    }))
+}
+
+/// Matches `remote_caps`' media codecs against `router_caps`', in `remote_caps`' declared order,
+/// pairing each matched media codec with its RTX codec (see [`match_rtx_codec`]) and assigning
+/// payload types via [`PayloadTypeAllocator`].
+pub fn match_codecs(
+    router_caps: &RtpCapabilities,
+    remote_caps: &RtpCapabilities,
+) -> Result<Vec<MatchedCodec>, OrtcError> {
+    let router_codecs = router_caps.codecs.as_deref().unwrap_or_default();
+    let remote_codecs = remote_caps.codecs.as_deref().unwrap_or_default();
+
+    let mut allocator = PayloadTypeAllocator::new();
+    let mut matched = Vec::new();
+
+    for remote_codec in remote_codecs
+        .iter()
+        .filter(|codec| !is_rtx_mime_type(&codec.mime_type))
+    {
+        let router_codec = router_codecs.iter().find(|candidate| {
+            !is_rtx_mime_type(&candidate.mime_type) && codecs_match(candidate, remote_codec)
+        });
+        let router_codec = match router_codec {
+            Some(codec) => codec,
+            None => continue,
+        };
+
+        let media_payload_type = allocator.allocate(remote_codec.preferred_payload_type)?;
+
+        let media_codec = RtpCodecParameters {
+            mime_type: router_codec.mime_type.clone(),
+            payload_type: media_payload_type,
+            clock_rate: router_codec.clock_rate,
+            channels: router_codec.channels,
+            parameters: remote_codec.parameters.clone(),
+            rtcp_feedback: Some(intersect_rtcp_feedback(
+                router_codec.rtcp_feedback.as_deref(),
+                remote_codec.rtcp_feedback.as_deref(),
+            )),
+        };
+
+        let rtx_codec = match_rtx_codec(
+            router_codecs,
+            remote_codecs,
+            remote_codec,
+            media_payload_type,
+            &mut allocator,
+        )?;
+
+        matched.push(MatchedCodec {
+            media_codec,
+            rtx_codec,
+        });
+    }
+
+    if matched.is_empty() {
+        return Err(OrtcError::NoMatchingCodec);
+    }
+
+    Ok(matched)
+}
+
+/// Intersects `router_caps`' and `remote_caps`' header extensions by URI. Per
+/// [`RtpHeaderExtension`]'s doc comment, only the router's [`RtpHeaderExtensionDirection`]
+/// matters (an endpoint's own `direction`, if present, is ignored), so an extension is dropped
+/// only when the router itself marked it `inactive`, or when it's restricted to a [`MediaKind`]
+/// the remote doesn't declare support for.
+pub fn match_header_extensions(
+    router_caps: &RtpCapabilities,
+    remote_caps: &RtpCapabilities,
+) -> Vec<RtpHeaderExtensionParameters> {
+    let router_extensions = router_caps.header_extensions.as_deref().unwrap_or_default();
+    let remote_extensions = remote_caps.header_extensions.as_deref().unwrap_or_default();
+
+    router_extensions
+        .iter()
+        .filter(|router_ext| router_ext.direction != RtpHeaderExtensionDirection::Inactive)
+        .filter(|router_ext| {
+            remote_extensions.iter().any(|remote_ext| {
+                remote_ext.uri == router_ext.uri
+                    && router_ext
+                        .kind
+                        .map_or(true, |kind| remote_ext.kind.map_or(true, |rk| rk == kind))
+            })
+        })
+        .map(|router_ext| RtpHeaderExtensionParameters {
+            uri: router_ext.uri.clone(),
+            id: router_ext.preferred_id,
+            encrypt: Some(router_ext.preferred_encrypt),
+            parameters: HashMap::new(),
+        })
+        .collect()
+}
+
+/// Produces the reduced [`RtpParameters`] a [`Consumer`](crate::consumer::Consumer) should use to
+/// receive media matching both the router's and the remote endpoint's [`RtpCapabilities`] —
+/// intersecting codecs (with RTX pairing) via [`match_codecs`] and header extensions via
+/// [`match_header_extensions`].
+pub fn get_consumer_rtp_parameters(
+    router_caps: &RtpCapabilities,
+    remote_caps: &RtpCapabilities,
+) -> Result<RtpParameters, OrtcError> {
+    let matched_codecs = match_codecs(router_caps, remote_caps)?;
+    let header_extensions = match_header_extensions(router_caps, remote_caps);
+
+    let mut codecs = Vec::new();
+    for matched in matched_codecs {
+        codecs.push(matched.media_codec);
+        if let Some(rtx_codec) = matched.rtx_codec {
+            codecs.push(rtx_codec);
+        }
+    }
+
+    Ok(RtpParameters {
+        mid: None,
+        codecs,
+        header_extensions: Some(header_extensions),
+        encodings: None,
+        rtcp: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codec(mime_type: &str, clock_rate: u32) -> RtpCodecCapability {
+        RtpCodecCapability {
+            kind: if mime_type.starts_with("audio/") {
+                MediaKind::Audio
+            } else {
+                MediaKind::Video
+            },
+            mime_type: mime_type.to_string(),
+            preferred_payload_type: None,
+            clock_rate,
+            channels: None,
+            parameters: HashMap::new(),
+            rtcp_feedback: None,
+        }
+    }
+
+    fn with_preferred_payload_type(
+        mut codec: RtpCodecCapability,
+        payload_type: u32,
+    ) -> RtpCodecCapability {
+        codec.preferred_payload_type = Some(payload_type);
+        codec
+    }
+
+    fn with_parameter(
+        mut codec: RtpCodecCapability,
+        key: &str,
+        value: &str,
+    ) -> RtpCodecCapability {
+        codec.parameters.insert(key.to_string(), value.to_string());
+        codec
+    }
+
+    fn rtx(apt: u32, clock_rate: u32) -> RtpCodecCapability {
+        with_parameter(codec("video/rtx", clock_rate), "apt", &apt.to_string())
+    }
+
+    fn capabilities(codecs: Vec<RtpCodecCapability>) -> RtpCapabilities {
+        RtpCapabilities {
+            codecs: Some(codecs),
+            header_extensions: None,
+            fec_mechanisms: None,
+        }
+    }
+
+    #[test]
+    fn codecs_match_requires_same_mime_type_and_clock_rate() {
+        let opus = codec("audio/opus", 48000);
+        assert!(codecs_match(&opus, &codec("audio/opus", 48000)));
+        assert!(!codecs_match(&opus, &codec("audio/opus", 8000)));
+        assert!(!codecs_match(&opus, &codec("audio/g722", 48000)));
+    }
+
+    #[test]
+    fn codecs_match_checks_audio_channel_count() {
+        let mut stereo = codec("audio/opus", 48000);
+        stereo.channels = Some(2);
+        let mut mono = codec("audio/opus", 48000);
+        mono.channels = Some(1);
+        assert!(!codecs_match(&stereo, &mono));
+        assert!(codecs_match(&stereo, &stereo.clone()));
+    }
+
+    #[test]
+    fn codecs_match_checks_h264_critical_parameters() {
+        let router = with_parameter(
+            with_parameter(codec("video/h264", 90000), "packetization-mode", "1"),
+            "profile-level-id",
+            "42e01f",
+        );
+        let matching_remote = with_parameter(
+            with_parameter(codec("video/h264", 90000), "packetization-mode", "1"),
+            "profile-level-id",
+            "42e01f",
+        );
+        let mismatching_remote = with_parameter(
+            with_parameter(codec("video/h264", 90000), "packetization-mode", "1"),
+            "profile-level-id",
+            "42e01e",
+        );
+        assert!(codecs_match(&router, &matching_remote));
+        assert!(!codecs_match(&router, &mismatching_remote));
+    }
+
+    #[test]
+    fn codecs_match_checks_vp9_profile_id() {
+        let router = with_parameter(codec("video/vp9", 90000), "profile-id", "0");
+        let matching_remote = with_parameter(codec("video/vp9", 90000), "profile-id", "0");
+        let mismatching_remote = with_parameter(codec("video/vp9", 90000), "profile-id", "2");
+        assert!(codecs_match(&router, &matching_remote));
+        assert!(!codecs_match(&router, &mismatching_remote));
+    }
+
+    #[test]
+    fn allocator_prefers_the_remote_payload_type_when_free_and_dynamic() {
+        let mut allocator = PayloadTypeAllocator::new();
+        assert_eq!(allocator.allocate(Some(111)), Ok(111));
+    }
+
+    #[test]
+    fn allocator_falls_back_to_next_dynamic_when_preferred_is_taken_or_out_of_range() {
+        let mut allocator = PayloadTypeAllocator::new();
+        assert_eq!(allocator.allocate(Some(96)), Ok(96));
+        // 96 is already used, so the next allocation skips it even though it was requested again.
+        assert_eq!(allocator.allocate(Some(96)), Ok(97));
+        // Outside the dynamic range entirely, so it falls back to the next free dynamic slot.
+        assert_eq!(allocator.allocate(Some(0)), Ok(98));
+    }
+
+    #[test]
+    fn allocator_errors_once_the_dynamic_range_is_exhausted() {
+        let mut allocator = PayloadTypeAllocator::new();
+        for expected in DYNAMIC_PAYLOAD_TYPE_MIN..=DYNAMIC_PAYLOAD_TYPE_MAX {
+            assert_eq!(allocator.allocate(None), Ok(expected));
+        }
+        assert_eq!(allocator.allocate(None), Err(OrtcError::NoMorePayloadTypes));
+    }
+
+    #[test]
+    fn match_rtx_codec_pairs_rtx_and_rewrites_apt_to_the_media_payload_type() {
+        let router_codecs = vec![codec("video/vp8", 90000), rtx(0, 90000)];
+        let remote_codecs = vec![
+            with_preferred_payload_type(codec("video/vp8", 90000), 96),
+            with_preferred_payload_type(rtx(96, 90000), 97),
+        ];
+        let remote_media_codec = &remote_codecs[0];
+        let mut allocator = PayloadTypeAllocator::new();
+        allocator.allocate(Some(96)).unwrap();
+
+        let rtx_codec =
+            match_rtx_codec(&router_codecs, &remote_codecs, remote_media_codec, 96, &mut allocator)
+                .unwrap()
+                .expect("router and remote both support RTX for this codec");
+
+        assert_eq!(rtx_codec.mime_type, "video/rtx");
+        assert_eq!(rtx_codec.parameters.get("apt"), Some(&"96".to_string()));
+        assert_eq!(rtx_codec.payload_type, 97);
+    }
+
+    #[test]
+    fn match_rtx_codec_returns_none_when_the_router_has_no_rtx_codec() {
+        let router_codecs = vec![codec("video/vp8", 90000)];
+        let remote_codecs = vec![
+            with_preferred_payload_type(codec("video/vp8", 90000), 96),
+            with_preferred_payload_type(rtx(96, 90000), 97),
+        ];
+        let remote_media_codec = &remote_codecs[0];
+        let mut allocator = PayloadTypeAllocator::new();
+        allocator.allocate(Some(96)).unwrap();
+
+        let rtx_codec = match_rtx_codec(
+            &router_codecs,
+            &remote_codecs,
+            remote_media_codec,
+            96,
+            &mut allocator,
+        )
+        .unwrap();
+
+        assert_eq!(rtx_codec, None);
+    }
+
+    #[test]
+    fn get_consumer_rtp_parameters_matches_codecs_and_pairs_rtx() {
+        let router_caps = capabilities(vec![codec("video/vp8", 90000), rtx(0, 90000)]);
+        let remote_caps = capabilities(vec![
+            with_preferred_payload_type(codec("video/vp8", 90000), 96),
+            with_preferred_payload_type(rtx(96, 90000), 97),
+        ]);
+
+        let parameters = get_consumer_rtp_parameters(&router_caps, &remote_caps).unwrap();
+
+        assert_eq!(parameters.codecs.len(), 2);
+        assert_eq!(parameters.codecs[0].mime_type, "video/vp8");
+        assert_eq!(parameters.codecs[1].mime_type, "video/rtx");
+        assert_eq!(
+            parameters.codecs[1].parameters.get("apt"),
+            Some(&parameters.codecs[0].payload_type.to_string())
+        );
+    }
+
+    #[test]
+    fn get_consumer_rtp_parameters_errors_when_nothing_matches() {
+        let router_caps = capabilities(vec![codec("video/vp8", 90000)]);
+        let remote_caps = capabilities(vec![codec("video/h264", 90000)]);
+
+        assert_eq!(
+            get_consumer_rtp_parameters(&router_caps, &remote_caps),
+            Err(OrtcError::NoMatchingCodec)
+        );
+    }
+}
@@ -10,9 +10,13 @@ use crate::data_producer::DataProducerId;
 use crate::data_structures::{AppData, WebRtcMessage};
 use crate::messages::{
     DataConsumerCloseRequest, DataConsumerDumpRequest, DataConsumerGetBufferedAmountRequest,
-    DataConsumerGetStatsRequest, DataConsumerInternal, DataConsumerSendRequest,
+    DataConsumerGetStatsRequest, DataConsumerGrantCreditsData, DataConsumerGrantCreditsRequest,
+    DataConsumerInternal, DataConsumerPauseRequest, DataConsumerResumeRequest,
+    DataConsumerSendBatchDescriptor, DataConsumerSendBatchRequest,
+    DataConsumerSendBatchRequestData, DataConsumerSendBatchResponse, DataConsumerSendRequest,
     DataConsumerSendRequestData, DataConsumerSetBufferedAmountLowThresholdData,
-    DataConsumerSetBufferedAmountLowThresholdRequest,
+    DataConsumerSetBufferedAmountLowThresholdRequest, DataConsumerSetPriorityData,
+    DataConsumerSetPriorityRequest,
 };
 use crate::sctp_parameters::SctpStreamParameters;
 use crate::transport::Transport;
@@ -22,12 +26,17 @@ use crate::worker::{
 };
 use async_executor::Executor;
 use event_listener_primitives::{Bag, BagOnce, HandlerId};
+use futures_lite::Stream;
 use log::*;
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::fmt::Debug;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU8, Ordering};
 use std::sync::{Arc, Weak};
+use std::task::{Context, Poll};
+use std::time::Duration;
 
 uuid_based_wrapper_type!(
     /// Data consumer identifier.
@@ -56,6 +65,20 @@ pub struct DataConsumerOptions {
     /// Defaults to the value in the DataProducer if it has type `Sctp` or unset if it has type
     /// `Direct`.
     pub(super) max_retransmits: Option<u16>,
+    /// The priority level this data consumer is dequeued at when the shared SCTP send buffer of
+    /// the transport is congested, relative to other data consumers on the same transport.
+    /// Higher values are drained first. Defaults to the worker's default priority level.
+    pub priority: Option<u8>,
+    /// Policy applied to messages received while the data consumer is paused.
+    ///
+    /// Only relevant for data consumers of type [`DataConsumerType::Direct`]; SCTP-based
+    /// consumers are flow-controlled by the underlying DataChannel itself.
+    pub overflow_policy: OverflowPolicy,
+    /// Requests a bounded window of messages already emitted by the data producer to be replayed
+    /// via [`DataConsumer::on_replay_message`] (live delivery is not held back for it, see there).
+    ///
+    /// Only relevant for data consumers of type [`DataConsumerType::Direct`].
+    pub replay: Option<ReplaySpec>,
     /// Custom application data.
     pub app_data: AppData,
 }
@@ -68,6 +91,9 @@ impl DataConsumerOptions {
             ordered: None,
             max_packet_life_time: None,
             max_retransmits: None,
+            priority: None,
+            overflow_policy: OverflowPolicy::default(),
+            replay: None,
             app_data: AppData::default(),
         }
     }
@@ -79,6 +105,9 @@ impl DataConsumerOptions {
             ordered: Some(true),
             max_packet_life_time: None,
             max_retransmits: None,
+            priority: None,
+            overflow_policy: OverflowPolicy::default(),
+            replay: None,
             app_data: AppData::default(),
         }
     }
@@ -90,6 +119,9 @@ impl DataConsumerOptions {
             ordered: None,
             max_packet_life_time: None,
             max_retransmits: None,
+            priority: None,
+            overflow_policy: OverflowPolicy::default(),
+            replay: None,
             app_data: AppData::default(),
         }
     }
@@ -105,6 +137,9 @@ impl DataConsumerOptions {
             ordered: None,
             max_packet_life_time: Some(max_packet_life_time),
             max_retransmits: None,
+            priority: None,
+            overflow_policy: OverflowPolicy::default(),
+            replay: None,
             app_data: AppData::default(),
         }
     }
@@ -119,11 +154,57 @@ impl DataConsumerOptions {
             ordered: None,
             max_packet_life_time: None,
             max_retransmits: Some(max_retransmits),
+            priority: None,
+            overflow_policy: OverflowPolicy::default(),
+            replay: None,
             app_data: AppData::default(),
         }
     }
 }
 
+/// Policy applied to messages received while a data consumer is paused, see
+/// [`DataConsumer::pause`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OverflowPolicy {
+    /// Buffer up to `capacity` messages, dropping newly-arriving ones once the buffer is full.
+    DropNewest {
+        /// Maximum number of buffered messages.
+        capacity: usize,
+    },
+    /// Buffer up to `capacity` messages, evicting the oldest buffered one to make room for a new
+    /// one once the buffer is full.
+    DropOldest {
+        /// Maximum number of buffered messages.
+        capacity: usize,
+    },
+    /// Buffer messages without a fixed capacity until the data consumer is resumed.
+    Block,
+}
+
+impl Default for OverflowPolicy {
+    fn default() -> Self {
+        OverflowPolicy::DropNewest {
+            capacity: DEFAULT_PAUSE_BUFFER_CAPACITY,
+        }
+    }
+}
+
+/// Default capacity of the buffer used to hold messages received while a data consumer is paused
+/// with [`OverflowPolicy::DropNewest`] or [`OverflowPolicy::DropOldest`].
+const DEFAULT_PAUSE_BUFFER_CAPACITY: usize = 1024;
+
+/// Requests that a newly created data consumer first receive a bounded window of messages its
+/// data producer already emitted, before switching over to live delivery, see
+/// [`DataConsumerOptions::replay`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ReplaySpec {
+    /// Replay at most this many of the most recently emitted messages.
+    pub max_messages: u32,
+    /// Only replay messages emitted within this duration before the data consumer was created.
+    pub max_age: Duration,
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[doc(hidden)]
@@ -166,6 +247,8 @@ pub enum DataConsumerType {
 #[serde(tag = "event", rename_all = "lowercase", content = "data")]
 enum Notification {
     DataProducerClose,
+    DataProducerPause,
+    DataProducerResume,
     SctpSendBufferFull,
     #[serde(rename_all = "camelCase")]
     BufferedAmountLow {
@@ -182,8 +265,12 @@ enum PayloadNotification {
 #[derive(Default)]
 struct Handlers {
     message: Bag<Box<dyn Fn(&WebRtcMessage) + Send + Sync>>,
+    replay_message: Bag<Box<dyn Fn(&WebRtcMessage) + Send + Sync>>,
     sctp_send_buffer_full: Bag<Box<dyn Fn() + Send + Sync>>,
     buffered_amount_low: Bag<Box<dyn Fn(u32) + Send + Sync>>,
+    pause: Bag<Box<dyn Fn() + Send + Sync>>,
+    resume: Bag<Box<dyn Fn() + Send + Sync>>,
+    credits_exhausted: Bag<Box<dyn Fn() + Send + Sync>>,
     data_producer_close: BagOnce<Box<dyn FnOnce() + Send>>,
     transport_close: BagOnce<Box<dyn FnOnce() + Send>>,
     close: BagOnce<Box<dyn FnOnce() + Send>>,
@@ -204,11 +291,30 @@ struct Inner {
     app_data: AppData,
     transport: Box<dyn Transport>,
     closed: AtomicBool,
+    priority: AtomicU8,
+    paused: AtomicBool,
+    overflow_policy: OverflowPolicy,
+    paused_message_buffer: Mutex<VecDeque<WebRtcMessage>>,
+    // Remaining number of messages the worker is allowed to forward before pausing delivery, see
+    // [`DirectDataConsumer::grant_credits`].
+    credits: AtomicU32,
+    // Whether `grant_credits` has ever been called. Delivery is only gated on `credits` reaching
+    // zero once the application has opted into pull-based flow control this way - otherwise every
+    // `DirectDataConsumer` would default to `credits == 0` and deliver nothing until the first
+    // grant.
+    credits_granted: AtomicBool,
+    // Messages to replay via `on_replay_message` once a first handler is registered, see
+    // [`DataConsumerOptions::replay`]. Taken (and thus delivered) at most once.
+    replay_messages: Mutex<Option<Vec<WebRtcMessage>>>,
     // Drop subscription to consumer-specific notifications when consumer itself is dropped
     _subscription_handlers: Vec<Option<SubscriptionHandler>>,
     _on_transport_close_handler: Mutex<HandlerId>,
 }
 
+/// Default priority level a data consumer is dequeued at when the shared SCTP send buffer is
+/// congested, used when [`DataConsumerOptions::priority`] is not given.
+const DEFAULT_PRIORITY: u8 = 1;
+
 impl Drop for Inner {
     fn drop(&mut self) {
         debug!("drop()");
@@ -247,6 +353,43 @@ impl Inner {
             }
         }
     }
+
+    // Buffers a message received while paused according to `overflow_policy`, instead of
+    // forwarding it to `message` handlers right away.
+    fn buffer_paused_message(&self, message: WebRtcMessage) {
+        let mut buffer = self.paused_message_buffer.lock();
+
+        match self.overflow_policy {
+            OverflowPolicy::DropNewest { capacity } => {
+                if buffer.len() < capacity {
+                    buffer.push_back(message);
+                } else {
+                    debug!("paused message buffer full, dropping newest message");
+                }
+            }
+            OverflowPolicy::DropOldest { capacity } => {
+                if buffer.len() >= capacity {
+                    buffer.pop_front();
+                }
+                buffer.push_back(message);
+            }
+            OverflowPolicy::Block => {
+                buffer.push_back(message);
+            }
+        }
+    }
+
+    // Delivers messages buffered while paused to `message` handlers, in the order they were
+    // received.
+    fn flush_paused_messages(&self) {
+        let messages = self.paused_message_buffer.lock().drain(..).collect::<Vec<_>>();
+
+        for message in messages {
+            self.handlers.message.call(|callback| {
+                callback(&message);
+            });
+        }
+    }
 }
 
 /// Data consumer created on transport other than
@@ -303,6 +446,9 @@ impl DataConsumer {
         executor: Arc<Executor<'static>>,
         channel: Channel,
         payload_channel: PayloadChannel,
+        priority: Option<u8>,
+        overflow_policy: OverflowPolicy,
+        replay_messages: Vec<WebRtcMessage>,
         app_data: AppData,
         transport: Box<dyn Transport>,
         direct: bool,
@@ -329,6 +475,31 @@ impl DataConsumer {
                                 inner.close();
                             }
                         }
+                        Notification::DataProducerPause => {
+                            if let Some(inner) = inner_weak
+                                .lock()
+                                .as_ref()
+                                .and_then(|weak_inner| weak_inner.upgrade())
+                            {
+                                let was_paused = inner.paused.swap(true, Ordering::SeqCst);
+                                if !was_paused {
+                                    handlers.pause.call_simple();
+                                }
+                            }
+                        }
+                        Notification::DataProducerResume => {
+                            if let Some(inner) = inner_weak
+                                .lock()
+                                .as_ref()
+                                .and_then(|weak_inner| weak_inner.upgrade())
+                            {
+                                let was_paused = inner.paused.swap(false, Ordering::SeqCst);
+                                if was_paused {
+                                    inner.flush_paused_messages();
+                                    handlers.resume.call_simple();
+                                }
+                            }
+                        }
                         Notification::SctpSendBufferFull => {
                             handlers.sctp_send_buffer_full.call_simple();
                         }
@@ -347,6 +518,7 @@ impl DataConsumer {
 
         let payload_subscription_handler = {
             let handlers = Arc::clone(&handlers);
+            let inner_weak = Arc::clone(&inner_weak);
 
             payload_channel.subscribe_to_notifications(id.into(), move |notification| {
                 let NotificationMessage { message, payload } = notification;
@@ -354,10 +526,55 @@ impl DataConsumer {
                     Ok(notification) => match notification {
                         PayloadNotification::Message { ppid } => {
                             let message = WebRtcMessage::new(ppid, payload);
-
-                            handlers.message.call(|callback| {
-                                callback(&message);
-                            });
+                            let buffered = inner_weak
+                                .lock()
+                                .as_ref()
+                                .and_then(|weak_inner| weak_inner.upgrade())
+                                .map(|inner| {
+                                    // Credits only gate delivery for a DirectDataConsumer that has
+                                    // opted in by calling grant_credits at least once - a
+                                    // RegularDataConsumer has no grant_credits to ever move
+                                    // `credits` off its default of 0, and a DirectDataConsumer
+                                    // that never calls grant_credits is unbounded, not permanently
+                                    // out of credits.
+                                    let out_of_credits = inner.direct
+                                        && inner.credits_granted.load(Ordering::SeqCst)
+                                        && {
+                                            let decrement =
+                                                |credits: u32| Some(credits.saturating_sub(1));
+                                            let previous_credits = inner
+                                                .credits
+                                                .fetch_update(
+                                                    Ordering::SeqCst,
+                                                    Ordering::SeqCst,
+                                                    decrement,
+                                                )
+                                                .unwrap_or(0);
+                                            if previous_credits == 1 {
+                                                inner.handlers.credits_exhausted.call_simple();
+                                            }
+                                            // `previous_credits == 0` means credits were already
+                                            // exhausted before this message arrived, not merely
+                                            // that this message is the last one permitted - that
+                                            // one still gets delivered, matching grant_credits's
+                                            // "forward up to n more messages" contract.
+                                            previous_credits == 0
+                                        };
+
+                                    if inner.paused.load(Ordering::SeqCst) || out_of_credits {
+                                        inner.buffer_paused_message(message.clone());
+                                        true
+                                    } else {
+                                        false
+                                    }
+                                })
+                                .unwrap_or(false);
+
+                            if !buffered {
+                                handlers.message.call(|callback| {
+                                    callback(&message);
+                                });
+                            }
                         }
                     },
                     Err(error) => {
@@ -396,6 +613,17 @@ impl DataConsumer {
             app_data,
             transport,
             closed: AtomicBool::new(false),
+            priority: AtomicU8::new(priority.unwrap_or(DEFAULT_PRIORITY)),
+            paused: AtomicBool::new(false),
+            overflow_policy,
+            paused_message_buffer: Mutex::new(VecDeque::new()),
+            credits: AtomicU32::new(0),
+            credits_granted: AtomicBool::new(false),
+            replay_messages: Mutex::new(if replay_messages.is_empty() {
+                None
+            } else {
+                Some(replay_messages)
+            }),
             _subscription_handlers: vec![subscription_handler, payload_subscription_handler],
             _on_transport_close_handler: Mutex::new(on_transport_close_handler),
         });
@@ -449,6 +677,17 @@ impl DataConsumer {
         self.inner().closed.load(Ordering::SeqCst)
     }
 
+    /// Priority used by the worker to decide dequeue order from the shared SCTP send buffer when
+    /// it is congested, higher values are drained first.
+    pub fn priority(&self) -> u8 {
+        self.inner().priority.load(Ordering::SeqCst)
+    }
+
+    /// Whether the data consumer is paused.
+    pub fn paused(&self) -> bool {
+        self.inner().paused.load(Ordering::SeqCst)
+    }
+
     /// Dump DataConsumer.
     #[doc(hidden)]
     pub async fn dump(&self) -> Result<DataConsumerDump, RequestError> {
@@ -518,6 +757,70 @@ impl DataConsumer {
             .await
     }
 
+    /// Sets the priority used by the worker to decide dequeue order from the shared SCTP send
+    /// buffer when it is congested, higher values are drained first.
+    pub async fn set_priority(&self, priority: u8) -> Result<(), RequestError> {
+        debug!("set_priority() [priority:{}]", priority);
+
+        self.inner()
+            .channel
+            .request(DataConsumerSetPriorityRequest {
+                internal: self.get_internal(),
+                data: DataConsumerSetPriorityData { priority },
+            })
+            .await?;
+
+        self.inner().priority.store(priority, Ordering::SeqCst);
+
+        Ok(())
+    }
+
+    /// Pauses the data consumer.
+    ///
+    /// # Notes on usage
+    /// While paused, messages received from the corresponding data producer are not forwarded to
+    /// [`DataConsumer::on_message`]/[`DirectDataConsumer::message_stream`] callbacks, but handled
+    /// according to [`DataConsumerOptions::overflow_policy`] instead, and delivered once
+    /// [`DataConsumer::resume`] is called.
+    pub async fn pause(&self) -> Result<(), RequestError> {
+        debug!("pause()");
+
+        self.inner()
+            .channel
+            .request(DataConsumerPauseRequest {
+                internal: self.get_internal(),
+            })
+            .await?;
+
+        let was_paused = self.inner().paused.swap(true, Ordering::SeqCst);
+        if !was_paused {
+            self.inner().handlers.pause.call_simple();
+        }
+
+        Ok(())
+    }
+
+    /// Resumes the data consumer after having been paused, flushing any messages buffered while
+    /// paused to [`DataConsumer::on_message`] callbacks.
+    pub async fn resume(&self) -> Result<(), RequestError> {
+        debug!("resume()");
+
+        self.inner()
+            .channel
+            .request(DataConsumerResumeRequest {
+                internal: self.get_internal(),
+            })
+            .await?;
+
+        let was_paused = self.inner().paused.swap(false, Ordering::SeqCst);
+        if was_paused {
+            self.inner().flush_paused_messages();
+            self.inner().handlers.resume.call_simple();
+        }
+
+        Ok(())
+    }
+
     /// Callback is called when a message has been received from the corresponding data producer.
     ///
     /// # Notes on usage
@@ -530,6 +833,35 @@ impl DataConsumer {
         self.inner().handlers.message.add(Box::new(callback))
     }
 
+    /// Callback is called once per message from [`DataConsumerOptions::replay`]'s catch-up
+    /// window, in the order the messages were originally emitted.
+    ///
+    /// # Notes on usage
+    /// The replay window is delivered synchronously to the first callback registered with this
+    /// method; register it right after creating the data consumer to avoid missing it. Live
+    /// [`DataConsumer::on_message`] delivery is not held back for the replay window: a live
+    /// message that arrives before this first registration is delivered ahead of it.
+    pub fn on_replay_message<F: Fn(&WebRtcMessage) + Send + Sync + 'static>(
+        &self,
+        callback: F,
+    ) -> HandlerId {
+        let handler_id = self
+            .inner()
+            .handlers
+            .replay_message
+            .add(Box::new(callback));
+
+        if let Some(replay_messages) = self.inner().replay_messages.lock().take() {
+            for message in replay_messages {
+                self.inner().handlers.replay_message.call(|callback| {
+                    callback(&message);
+                });
+            }
+        }
+
+        handler_id
+    }
+
     /// Callback is called when a message could not be sent because the SCTP send buffer was full.
     pub fn on_sctp_send_buffer_full<F: Fn() + Send + Sync + 'static>(
         &self,
@@ -556,6 +888,18 @@ impl DataConsumer {
             .add(Box::new(callback))
     }
 
+    /// Callback is called when the data consumer is paused, whether by calling
+    /// [`DataConsumer::pause`] or because the associated data producer was paused.
+    pub fn on_pause<F: Fn() + Send + Sync + 'static>(&self, callback: F) -> HandlerId {
+        self.inner().handlers.pause.add(Box::new(callback))
+    }
+
+    /// Callback is called when the data consumer is resumed, whether by calling
+    /// [`DataConsumer::resume`] or because the associated data producer was resumed.
+    pub fn on_resume<F: Fn() + Send + Sync + 'static>(&self, callback: F) -> HandlerId {
+        self.inner().handlers.resume.add(Box::new(callback))
+    }
+
     /// Callback is called when the associated data producer is closed for whatever reason. The data
     /// consumer itself is also closed.
     pub fn on_data_producer_close<F: FnOnce() + Send + 'static>(&self, callback: F) -> HandlerId {
@@ -630,6 +974,143 @@ impl DirectDataConsumer {
             )
             .await
     }
+
+    /// Sends multiple direct messages to the worker in a single round-trip, concatenating their
+    /// payloads into one buffer instead of issuing one [`DirectDataConsumer::send`] request per
+    /// message. Intended for high-rate senders where per-message channel overhead dominates.
+    ///
+    /// Ordering is preserved, and the returned vector reports success or failure for each
+    /// message individually (in the same order as `messages`), so a partial failure partway
+    /// through the batch, e.g. the SCTP send buffer becoming full, doesn't hide the messages
+    /// that did go through.
+    pub async fn send_batch(&self, messages: Vec<WebRtcMessage>) -> Vec<Result<(), RequestError>> {
+        if messages.is_empty() {
+            return Vec::new();
+        }
+
+        let message_count = messages.len();
+        let mut payload = Vec::new();
+        let mut descriptors = Vec::with_capacity(message_count);
+
+        for message in messages {
+            let (ppid, message_payload) = message.into_ppid_and_payload();
+            descriptors.push(DataConsumerSendBatchDescriptor {
+                ppid,
+                offset: payload.len(),
+                len: message_payload.len(),
+            });
+            payload.extend_from_slice(&message_payload);
+        }
+
+        let response = self
+            .inner
+            .payload_channel
+            .request(
+                DataConsumerSendBatchRequest {
+                    internal: DataConsumerInternal {
+                        router_id: self.inner.transport.router_id(),
+                        transport_id: self.inner.transport.id(),
+                        data_consumer_id: self.inner.id,
+                        data_producer_id: self.inner.data_producer_id,
+                    },
+                    data: DataConsumerSendBatchRequestData { descriptors },
+                },
+                payload,
+            )
+            .await;
+
+        match response {
+            Ok(DataConsumerSendBatchResponse { failed_indices }) => (0..message_count)
+                .map(|index| {
+                    if failed_indices.contains(&index) {
+                        Err(RequestError::Response {
+                            reason: "message rejected by worker".to_string(),
+                        })
+                    } else {
+                        Ok(())
+                    }
+                })
+                .collect(),
+            Err(error) => vec![Err(error); message_count],
+        }
+    }
+
+    /// Returns a [`Stream`] of messages received from the corresponding data producer, as an
+    /// alternative to [`DataConsumer::on_message`] for `async` code.
+    ///
+    /// Internally messages are forwarded into a bounded channel; the underlying `on_message`
+    /// handler is unregistered once the returned stream is dropped.
+    pub fn message_stream(&self) -> DataConsumerMessageStream {
+        let (sender, receiver) = async_channel::bounded(MESSAGE_STREAM_CAPACITY);
+
+        let handler_id = self.inner.handlers.message.add(Box::new(move |message| {
+            let _ = sender.try_send(message.clone());
+        }));
+
+        DataConsumerMessageStream {
+            receiver,
+            _handler_id: handler_id,
+        }
+    }
+
+    /// Grants the worker permission to forward up to `credits` additional messages, decrementing
+    /// as each one is emitted and pausing delivery again once exhausted.
+    ///
+    /// # Notes on usage
+    /// Gives the application authoritative, pull-based flow control over delivery regardless of
+    /// transport type, unlike [`DataConsumer::set_buffered_amount_low_threshold`] which only
+    /// reacts to the underlying SCTP send buffer draining. Call again, e.g. from
+    /// [`DirectDataConsumer::on_credits_exhausted`], to keep messages flowing.
+    pub async fn grant_credits(&self, credits: u32) -> Result<(), RequestError> {
+        debug!("grant_credits() [credits:{}]", credits);
+
+        self.inner
+            .channel
+            .request(DataConsumerGrantCreditsRequest {
+                internal: DataConsumerInternal {
+                    router_id: self.inner.transport.router_id(),
+                    transport_id: self.inner.transport.id(),
+                    data_consumer_id: self.inner.id,
+                    data_producer_id: self.inner.data_producer_id,
+                },
+                data: DataConsumerGrantCreditsData { credits },
+            })
+            .await?;
+
+        self.inner.credits.fetch_add(credits, Ordering::SeqCst);
+        self.inner.credits_granted.store(true, Ordering::SeqCst);
+
+        Ok(())
+    }
+
+    /// Callback is called once previously granted credits have all been consumed by incoming
+    /// messages and delivery has paused until more are granted via
+    /// [`DirectDataConsumer::grant_credits`].
+    pub fn on_credits_exhausted<F: Fn() + Send + Sync + 'static>(&self, callback: F) -> HandlerId {
+        self.inner
+            .handlers
+            .credits_exhausted
+            .add(Box::new(callback))
+    }
+}
+
+/// Capacity of the bounded channel backing [`DirectDataConsumer::message_stream`].
+const MESSAGE_STREAM_CAPACITY: usize = 1024;
+
+/// A [`Stream`] of messages produced by [`DirectDataConsumer::message_stream`].
+///
+/// Dropping the stream unregisters the underlying `on_message` handler.
+pub struct DataConsumerMessageStream {
+    receiver: async_channel::Receiver<WebRtcMessage>,
+    _handler_id: HandlerId,
+}
+
+impl Stream for DataConsumerMessageStream {
+    type Item = WebRtcMessage;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.get_mut().receiver).poll_next(cx)
+    }
 }
 
 /// [`WeakDataConsumer`] doesn't own data consumer instance on mediasoup-worker and will not prevent
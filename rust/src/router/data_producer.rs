@@ -5,23 +5,32 @@
 //! those messages, or can directly send them from the Rust application if the data producer was
 //! created on top of a [`DirectTransport`](crate::direct_transport::DirectTransport).
 
+use crate::data_consumer::ReplaySpec;
 use crate::data_structures::{AppData, WebRtcMessage};
 use crate::messages::{
-    DataProducerCloseRequest, DataProducerDumpRequest, DataProducerGetStatsRequest,
-    DataProducerInternal, DataProducerSendData, DataProducerSendNotification,
+    DataProducerCloseRequest, DataProducerDumpRequest, DataProducerGetBufferedAmountRequest,
+    DataProducerGetStatsRequest, DataProducerInternal, DataProducerPauseRequest,
+    DataProducerResumeRequest, DataProducerSendData, DataProducerSendManyData,
+    DataProducerSendManyNotification, DataProducerSendNotification,
+    DataProducerSetBufferedAmountLowThresholdData,
+    DataProducerSetBufferedAmountLowThresholdRequest,
 };
 use crate::sctp_parameters::SctpStreamParameters;
 use crate::transport::Transport;
 use crate::uuid_based_wrapper_type;
-use crate::worker::{Channel, NotificationError, PayloadChannel, RequestError};
+use crate::worker::{
+    Channel, NotificationError, PayloadChannel, RequestError, SubscriptionHandler,
+};
 use async_executor::Executor;
-use event_listener_primitives::{BagOnce, HandlerId};
+use event_listener_primitives::{Bag, BagOnce, HandlerId};
 use log::*;
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Weak};
+use std::time::Instant;
 
 uuid_based_wrapper_type!(
     /// Data producer identifier.
@@ -119,8 +128,20 @@ pub struct DataProducerStat {
     pub bytes_received: usize,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(tag = "event", rename_all = "lowercase", content = "data")]
+enum Notification {
+    #[serde(rename_all = "camelCase")]
+    BufferedAmountLow {
+        buffered_amount: u32,
+    },
+}
+
 #[derive(Default)]
 struct Handlers {
+    pause: Bag<Box<dyn Fn() + Send + Sync>>,
+    resume: Bag<Box<dyn Fn() + Send + Sync>>,
+    buffered_amount_low: Bag<Box<dyn Fn(u32) + Send + Sync>>,
     transport_close: BagOnce<Box<dyn FnOnce() + Send>>,
     close: BagOnce<Box<dyn FnOnce() + Send>>,
 }
@@ -139,9 +160,20 @@ struct Inner {
     app_data: AppData,
     transport: Box<dyn Transport>,
     closed: AtomicBool,
+    paused: AtomicBool,
+    // Bounded history of messages sent via `send`/`send_many`, used to serve
+    // [`DataConsumerOptions::replay`] requests for newly created data consumers.
+    replay_buffer: Mutex<VecDeque<(Instant, WebRtcMessage)>>,
+    _subscription_handler: Option<SubscriptionHandler>,
     _on_transport_close_handler: Mutex<HandlerId>,
 }
 
+/// Maximum number of sent messages kept around to serve [`DataConsumerOptions::replay`] requests,
+/// regardless of any individual request's `max_messages`.
+///
+/// [`DataConsumerOptions::replay`]: crate::data_consumer::DataConsumerOptions::replay
+const REPLAY_BUFFER_CAPACITY: usize = 1024;
+
 impl Drop for Inner {
     fn drop(&mut self) {
         debug!("drop()");
@@ -179,6 +211,15 @@ impl Inner {
             }
         }
     }
+
+    // Records a sent message for later replay, evicting the oldest one once the buffer is full.
+    fn record_for_replay(&self, message: WebRtcMessage) {
+        let mut buffer = self.replay_buffer.lock();
+        if buffer.len() >= REPLAY_BUFFER_CAPACITY {
+            buffer.pop_front();
+        }
+        buffer.push_back((Instant::now(), message));
+    }
 }
 
 /// Data producer created on transport other than
@@ -242,6 +283,22 @@ impl DataProducer {
         let handlers = Arc::<Handlers>::default();
 
         let inner_weak = Arc::<Mutex<Option<Weak<Inner>>>>::default();
+        let subscription_handler = {
+            let handlers = Arc::clone(&handlers);
+
+            channel.subscribe_to_notifications(id.into(), move |notification| {
+                match serde_json::from_value::<Notification>(notification) {
+                    Ok(Notification::BufferedAmountLow { buffered_amount }) => {
+                        handlers.buffered_amount_low.call(|callback| {
+                            callback(buffered_amount);
+                        });
+                    }
+                    Err(error) => {
+                        error!("Failed to parse notification: {}", error);
+                    }
+                }
+            })
+        };
         let on_transport_close_handler = transport.on_close({
             let inner_weak = Arc::clone(&inner_weak);
 
@@ -270,6 +327,9 @@ impl DataProducer {
             app_data,
             transport,
             closed: AtomicBool::new(false),
+            paused: AtomicBool::new(false),
+            replay_buffer: Mutex::new(VecDeque::new()),
+            _subscription_handler: subscription_handler,
             _on_transport_close_handler: Mutex::new(on_transport_close_handler),
         });
 
@@ -317,6 +377,51 @@ impl DataProducer {
         self.inner().closed.load(Ordering::SeqCst)
     }
 
+    /// Whether the data producer is paused.
+    pub fn paused(&self) -> bool {
+        self.inner().paused.load(Ordering::SeqCst)
+    }
+
+    /// Pauses the data producer, causing the worker to drop any further incoming SCTP/direct
+    /// messages instead of forwarding them to this data producer's consumers.
+    pub async fn pause(&self) -> Result<(), RequestError> {
+        debug!("pause()");
+
+        self.inner()
+            .channel
+            .request(DataProducerPauseRequest {
+                internal: self.get_internal(),
+            })
+            .await?;
+
+        let was_paused = self.inner().paused.swap(true, Ordering::SeqCst);
+        if !was_paused {
+            self.inner().handlers.pause.call_simple();
+        }
+
+        Ok(())
+    }
+
+    /// Resumes the data producer after having been paused, letting messages flow to consumers
+    /// again.
+    pub async fn resume(&self) -> Result<(), RequestError> {
+        debug!("resume()");
+
+        self.inner()
+            .channel
+            .request(DataProducerResumeRequest {
+                internal: self.get_internal(),
+            })
+            .await?;
+
+        let was_paused = self.inner().paused.swap(false, Ordering::SeqCst);
+        if was_paused {
+            self.inner().handlers.resume.call_simple();
+        }
+
+        Ok(())
+    }
+
     /// Dump DataProducer.
     #[doc(hidden)]
     pub async fn dump(&self) -> Result<DataProducerDump, RequestError> {
@@ -330,6 +435,42 @@ impl DataProducer {
             .await
     }
 
+    /// Returns the number of bytes of data currently buffered to be sent over the underlying
+    /// SCTP send buffer (just applicable for data producers of type `Direct`).
+    pub async fn get_buffered_amount(&self) -> Result<u32, RequestError> {
+        debug!("get_buffered_amount()");
+
+        let response = self
+            .inner()
+            .channel
+            .request(DataProducerGetBufferedAmountRequest {
+                internal: self.get_internal(),
+            })
+            .await?;
+
+        Ok(response.buffered_amount)
+    }
+
+    /// Sets the threshold, in bytes, at which the SCTP send buffer is considered drained again,
+    /// triggering [`DataProducer::on_buffered_amount_low`] callbacks.
+    pub async fn set_buffered_amount_low_threshold(
+        &self,
+        threshold: u32,
+    ) -> Result<(), RequestError> {
+        debug!(
+            "set_buffered_amount_low_threshold() [threshold:{}]",
+            threshold
+        );
+
+        self.inner()
+            .channel
+            .request(DataProducerSetBufferedAmountLowThresholdRequest {
+                internal: self.get_internal(),
+                data: DataProducerSetBufferedAmountLowThresholdData { threshold },
+            })
+            .await
+    }
+
     /// Returns current statistics of the data producer.
     ///
     /// Check the [RTC Statistics](https://mediasoup.org/documentation/v3/mediasoup/rtc-statistics/)
@@ -345,6 +486,31 @@ impl DataProducer {
             .await
     }
 
+    /// Callback is called when the data producer is paused.
+    pub fn on_pause<F: Fn() + Send + Sync + 'static>(&self, callback: F) -> HandlerId {
+        self.inner().handlers.pause.add(Box::new(callback))
+    }
+
+    /// Callback is called when the data producer is resumed.
+    pub fn on_resume<F: Fn() + Send + Sync + 'static>(&self, callback: F) -> HandlerId {
+        self.inner().handlers.resume.add(Box::new(callback))
+    }
+
+    /// Emitted when the underlying SCTP send buffer, shared with `send_many`, drops down to the
+    /// value set with [`DataProducer::set_buffered_amount_low_threshold`].
+    ///
+    /// # Notes on usage
+    /// Only applicable for data producers of type `Direct`.
+    pub fn on_buffered_amount_low<F: Fn(u32) + Send + Sync + 'static>(
+        &self,
+        callback: F,
+    ) -> HandlerId {
+        self.inner()
+            .handlers
+            .buffered_amount_low
+            .add(Box::new(callback))
+    }
+
     /// Callback is called when the transport this data producer belongs to is closed for whatever
     /// reason. The producer itself is also closed. A `on_data_producer_close` callback is called on
     /// all its associated consumers.
@@ -396,6 +562,8 @@ impl DataProducer {
 impl DirectDataProducer {
     /// Sends direct messages from the Rust process.
     pub async fn send(&self, message: WebRtcMessage) -> Result<(), NotificationError> {
+        self.inner.record_for_replay(message.clone());
+
         let (ppid, payload) = message.into_ppid_and_payload();
 
         self.inner
@@ -413,6 +581,68 @@ impl DirectDataProducer {
             )
             .await
     }
+
+    /// Sends multiple direct messages from the Rust process in a single notification, coalescing
+    /// their payloads instead of issuing one `payload_channel.notify` per message.
+    ///
+    /// Useful when pushing a large number of small messages, where per-notification overhead
+    /// would otherwise dominate. Check [`DataProducer::get_buffered_amount`] and
+    /// [`DataProducer::on_buffered_amount_low`] to avoid overflowing the worker's SCTP send
+    /// buffer when sending in bulk.
+    pub async fn send_many(
+        &self,
+        messages: impl IntoIterator<Item = WebRtcMessage>,
+    ) -> Result<(), NotificationError> {
+        let mut ppids = Vec::new();
+        let mut lens = Vec::new();
+        let mut payload = Vec::new();
+
+        for message in messages {
+            self.inner.record_for_replay(message.clone());
+
+            let (ppid, message_payload) = message.into_ppid_and_payload();
+            ppids.push(ppid);
+            lens.push(message_payload.len());
+            payload.extend_from_slice(&message_payload);
+        }
+
+        self.inner
+            .payload_channel
+            .notify(
+                DataProducerSendManyNotification {
+                    internal: DataProducerInternal {
+                        router_id: self.inner.transport.router_id(),
+                        transport_id: self.inner.transport.id(),
+                        data_producer_id: self.inner.id,
+                    },
+                    data: DataProducerSendManyData { ppids, lens },
+                },
+                payload.into(),
+            )
+            .await
+    }
+
+    /// Returns the most recent sent messages matching `spec`, in the order they were sent.
+    ///
+    /// Used to seed [`DataConsumerOptions::replay`] for a newly created data consumer of this
+    /// data producer.
+    ///
+    /// [`DataConsumerOptions::replay`]: crate::data_consumer::DataConsumerOptions::replay
+    pub(crate) fn replay_snapshot(&self, spec: ReplaySpec) -> Vec<WebRtcMessage> {
+        let buffer = self.inner.replay_buffer.lock();
+        let now = Instant::now();
+
+        let mut messages = buffer
+            .iter()
+            .rev()
+            .filter(|(sent_at, _)| now.duration_since(*sent_at) <= spec.max_age)
+            .take(spec.max_messages as usize)
+            .map(|(_, message)| message.clone())
+            .collect::<Vec<_>>();
+        messages.reverse();
+
+        messages
+    }
 }
 
 /// Same as [`DataProducer`], but will not be closed when dropped.
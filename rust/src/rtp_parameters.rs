@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
 
 /// The RTP capabilities define what mediasoup or an endpoint can receive at media level.
 #[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize)]
@@ -14,10 +18,9 @@ pub struct RtpCapabilities {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub header_extensions: Option<Vec<RtpHeaderExtension>>,
     // TODO: Does this need to be optional or can be an empty vec?
-    // TODO: Enum instead of string?
     /// Supported FEC mechanisms.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fec_mechanisms: Option<Vec<String>>,
+    pub fec_mechanisms: Option<Vec<FecMechanism>>,
 }
 
 /// Media kind
@@ -28,6 +31,110 @@ pub enum MediaKind {
     Video,
 }
 
+/// Forward error correction mechanism that can be advertised alongside the regular media codecs,
+/// letting a receiver recover lost packets without a retransmission round-trip.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum FecMechanism {
+    /// Redundant Audio/video Data, RFC 2198.
+    #[serde(rename = "RED")]
+    Red,
+    /// Generic FEC with ULP/SMPTE 2022-5, RFC 5109.
+    #[serde(rename = "ULPFEC")]
+    UlpFec,
+    /// Flexible FEC, currently at draft-ietf-payload-flexible-fec-scheme-03.
+    #[serde(rename = "FLEXFEC")]
+    FlexFec,
+}
+
+impl FecMechanism {
+    /// The MIME type of the codec entry that has to back this mechanism in
+    /// [`RtpCapabilities::codecs`] for [`RtpCapabilities::validate_fec_mechanisms`] to accept it.
+    fn supporting_codec_mime_type(self) -> &'static str {
+        match self {
+            FecMechanism::Red => "video/red",
+            FecMechanism::UlpFec => "video/ulpfec",
+            FecMechanism::FlexFec => "video/flexfec-03",
+        }
+    }
+}
+
+fn is_fec_codec_mime_type(mime_type: &str) -> bool {
+    [
+        FecMechanism::Red,
+        FecMechanism::UlpFec,
+        FecMechanism::FlexFec,
+    ]
+    .iter()
+    .any(|mechanism| mime_type.eq_ignore_ascii_case(mechanism.supporting_codec_mime_type()))
+}
+
+/// Error returned by [`RtpCapabilities::validate_fec_mechanisms`] when a declared
+/// [`FecMechanism`] isn't actually backed by a matching codec entry.
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum FecMechanismError {
+    /// No codec entry with the mechanism's expected MIME type (e.g. `video/red`) was found.
+    #[error("{mechanism:?} requires a {mime_type:?} codec entry, none was found")]
+    MissingCodec {
+        /// The mechanism that's missing its supporting codec.
+        mechanism: FecMechanism,
+        /// The MIME type that was expected but not found.
+        mime_type: &'static str,
+    },
+    /// The mechanism's supporting codec's clock rate doesn't match a video media codec it would
+    /// need to protect.
+    #[error(
+        "{mechanism:?}'s {mime_type:?} codec has clock rate {fec_clock_rate}, which doesn't \
+         match video codec {media_mime_type:?}'s clock rate {media_clock_rate}"
+    )]
+    ClockRateMismatch {
+        /// The mechanism whose supporting codec has a mismatched clock rate.
+        mechanism: FecMechanism,
+        /// The MIME type of the mechanism's supporting codec.
+        mime_type: &'static str,
+        /// The supporting codec's clock rate.
+        fec_clock_rate: u32,
+        /// The video media codec whose clock rate didn't match.
+        media_mime_type: String,
+        /// The video media codec's clock rate.
+        media_clock_rate: u32,
+    },
+}
+
+impl RtpCapabilities {
+    /// Checks that every mechanism in [`Self::fec_mechanisms`] is backed by a codec entry in
+    /// [`Self::codecs`] with the mechanism's expected MIME type (`video/red`, `video/ulpfec`, or
+    /// `video/flexfec-03`), and that codec's clock rate matches every video media codec declared
+    /// alongside it — so a mechanism is never advertised without the codec support it implies.
+    pub fn validate_fec_mechanisms(&self) -> Result<(), FecMechanismError> {
+        let codecs = self.codecs.as_deref().unwrap_or_default();
+        let media_video_codecs = codecs.iter().filter(|codec| {
+            codec.kind == MediaKind::Video && !is_fec_codec_mime_type(&codec.mime_type)
+        });
+
+        for mechanism in self.fec_mechanisms.iter().flatten().copied() {
+            let mime_type = mechanism.supporting_codec_mime_type();
+            let fec_codec = codecs
+                .iter()
+                .find(|codec| codec.mime_type.eq_ignore_ascii_case(mime_type))
+                .ok_or(FecMechanismError::MissingCodec { mechanism, mime_type })?;
+
+            for media_codec in media_video_codecs.clone() {
+                if media_codec.clock_rate != fec_codec.clock_rate {
+                    return Err(FecMechanismError::ClockRateMismatch {
+                        mechanism,
+                        mime_type,
+                        fec_clock_rate: fec_codec.clock_rate,
+                        media_mime_type: media_codec.mime_type.clone(),
+                        media_clock_rate: media_codec.clock_rate,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 // TODO: supportedRtpCapabilities.ts file and generally update TypeScript references
 /// Provides information on the capabilities of a codec within the RTP capabilities. The list of
 /// media codecs supported by mediasoup and their settings is defined in the
@@ -194,21 +301,393 @@ pub struct RtpCodecParameters {
 /// Provides information on RTCP feedback messages for a specific codec. Those messages can be
 /// transport layer feedback messages or codec-specific feedback messages. The list of RTCP
 /// feedbacks supported by mediasoup is defined in the supportedRtpCapabilities.ts file.
+///
+/// Modeled on WebRTC's `RtcpFeedbackType`/parameter pairs rather than the free-form
+/// `type`/`parameter` strings mediasoup's wire format uses, so callers can't build a
+/// type/parameter combination mediasoup doesn't actually support. Still round-trips through that
+/// same `{"type": "...", "parameter": "..."}` wire shape via [`RtcpFeedbackWire`], by way of
+/// [`From<RtcpFeedback>`]/[`TryFrom<RtcpFeedbackWire>`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "RtcpFeedbackWire", into = "RtcpFeedbackWire")]
+pub enum RtcpFeedback {
+    /// Generic NACK.
+    Nack,
+    /// NACK used for picture loss indication.
+    NackPli,
+    /// Codec control message used for full intra request.
+    CcmFir,
+    /// Google's "remote estimate max bitrate" congestion control feedback.
+    GoogRemb,
+    /// Transport-wide congestion control feedback.
+    TransportCC,
+    /// Google's loss notification feedback (`goog-lntf`), reporting the last decodable frame.
+    Lntf,
+}
+
+impl RtcpFeedback {
+    /// The wire-format `type` and optional `parameter` strings for this feedback, e.g. `("nack",
+    /// Some("pli"))`. Used to write `a=rtcp-fb` SDP lines in [`crate::sdp`].
+    pub(crate) fn wire_type_and_parameter(self) -> (&'static str, Option<&'static str>) {
+        match self {
+            RtcpFeedback::Nack => ("nack", None),
+            RtcpFeedback::NackPli => ("nack", Some("pli")),
+            RtcpFeedback::CcmFir => ("ccm", Some("fir")),
+            RtcpFeedback::GoogRemb => ("goog-remb", None),
+            RtcpFeedback::TransportCC => ("transport-cc", None),
+            RtcpFeedback::Lntf => ("goog-lntf", None),
+        }
+    }
+}
+
+/// The `{"type": ..., "parameter": ...}` shape [`RtcpFeedback`] actually serializes as, matching
+/// mediasoup's wire format.
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
-pub struct RtcpFeedback {
-    // TODO: Enum?
-    /// RTCP feedback type.
-    pub r#type: String,
-    /// RTCP feedback parameter.
+struct RtcpFeedbackWire {
+    r#type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    parameter: Option<String>,
+}
+
+/// Error returned when a wire-format RTCP feedback `type`/`parameter` pair doesn't match any
+/// [`RtcpFeedback`] variant mediasoup recognizes.
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+#[error("unsupported RTCP feedback type/parameter pair: type {r#type:?}, parameter {parameter:?}")]
+pub struct RtcpFeedbackError {
+    /// The unrecognized wire-format feedback type.
+    pub r#type: String,
+    /// The unrecognized wire-format feedback parameter, if any.
     pub parameter: Option<String>,
 }
 
+impl TryFrom<RtcpFeedbackWire> for RtcpFeedback {
+    type Error = RtcpFeedbackError;
+
+    fn try_from(wire: RtcpFeedbackWire) -> Result<Self, Self::Error> {
+        match (wire.r#type.as_str(), wire.parameter.as_deref()) {
+            ("nack", None) => Ok(RtcpFeedback::Nack),
+            ("nack", Some("pli")) => Ok(RtcpFeedback::NackPli),
+            ("ccm", Some("fir")) => Ok(RtcpFeedback::CcmFir),
+            ("goog-remb", None) => Ok(RtcpFeedback::GoogRemb),
+            ("transport-cc", None) => Ok(RtcpFeedback::TransportCC),
+            ("goog-lntf", None) => Ok(RtcpFeedback::Lntf),
+            _ => Err(RtcpFeedbackError {
+                r#type: wire.r#type,
+                parameter: wire.parameter,
+            }),
+        }
+    }
+}
+
+impl From<RtcpFeedback> for RtcpFeedbackWire {
+    fn from(feedback: RtcpFeedback) -> Self {
+        let (r#type, parameter) = match feedback {
+            RtcpFeedback::Nack => ("nack", None),
+            RtcpFeedback::NackPli => ("nack", Some("pli")),
+            RtcpFeedback::CcmFir => ("ccm", Some("fir")),
+            RtcpFeedback::GoogRemb => ("goog-remb", None),
+            RtcpFeedback::TransportCC => ("transport-cc", None),
+            RtcpFeedback::Lntf => ("goog-lntf", None),
+        };
+
+        RtcpFeedbackWire {
+            r#type: r#type.to_string(),
+            parameter: parameter.map(str::to_string),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 pub struct RtpEncodingParametersRtx {
     ssrc: u32,
 }
 
+/// How a sender should degrade an encoding's quality when bandwidth or CPU is constrained.
+/// Reacted to by the worker when it adjusts bitrate in response to REMB/transport-cc estimates.
+/// Screen-sharing wants [`Self::MaintainResolution`] (text must stay legible); camera feeds
+/// usually want [`Self::MaintainFramerate`] (motion must stay smooth).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DegradationPreference {
+    /// Degrade resolution before framerate.
+    MaintainFramerate,
+    /// Degrade framerate before resolution.
+    MaintainResolution,
+    /// Degrade a roughly even mix of resolution and framerate.
+    Balanced,
+    /// Don't degrade either; drop the encoding instead once bandwidth can't sustain it.
+    Disabled,
+}
+
+impl Default for DegradationPreference {
+    fn default() -> Self {
+        DegradationPreference::Balanced
+    }
+}
+
+/// An absolute target resolution for an encoding, in pixels.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Resolution {
+    /// Target width, in pixels.
+    pub width: u32,
+    /// Target height, in pixels.
+    pub height: u32,
+}
+
+/// Whether a [`ScalabilityMode`] has a full inter-layer dependency structure (`L`, upper spatial
+/// layers depend on lower ones) or is simulcast (`S`, each spatial layer is independently
+/// decodable).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ScalabilityModeStructure {
+    /// `L`: full SVC.
+    Layered,
+    /// `S`: simulcast.
+    Simulcast,
+}
+
+/// The resolution ratio between consecutive spatial layers of a [`ScalabilityMode`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ScalabilityModeResolutionRatio {
+    /// Each spatial layer doubles the resolution of the one below it. The default.
+    Two,
+    /// Each spatial layer is 1.5x the resolution of the one below it, marked by a trailing `h`.
+    OneAndAHalf,
+}
+
+/// Maximum spatial layer count the `L`/`S`...`T`... notation supports.
+const SCALABILITY_MODE_MAX_SPATIAL_LAYERS: u8 = 3;
+/// Maximum temporal layer count the `L`/`S`...`T`... notation supports.
+const SCALABILITY_MODE_MAX_TEMPORAL_LAYERS: u8 = 3;
+
+/// Error returned when a scalability mode string doesn't match the `L`/`S`...`T`... notation
+/// [`ScalabilityMode::from_str`] parses.
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum ScalabilityModeParseError {
+    /// Doesn't start with `L` (full SVC) or `S` (simulcast).
+    #[error("scalability mode {0:?} does not start with 'L' or 'S'")]
+    UnknownStructure(String),
+    /// Missing the `T` separating spatial and temporal layer counts.
+    #[error("scalability mode {0:?} is missing a 'T' separating spatial/temporal layer counts")]
+    MissingTemporalSeparator(String),
+    /// The spatial or temporal layer count isn't a valid number.
+    #[error("scalability mode {0:?} has a malformed spatial or temporal layer count")]
+    MalformedLayerCount(String),
+    /// The spatial or temporal layer count is zero.
+    #[error("scalability mode {0:?} has a zero spatial or temporal layer count")]
+    ZeroLayerCount(String),
+    /// The spatial or temporal layer count exceeds the notation's maximum.
+    #[error(
+        "scalability mode {0:?} exceeds the maximum of {SCALABILITY_MODE_MAX_SPATIAL_LAYERS} \
+         spatial/{SCALABILITY_MODE_MAX_TEMPORAL_LAYERS} temporal layers"
+    )]
+    TooManyLayers(String),
+    /// Trailing characters after the layer counts aren't a recognized `_KEY`/`h` suffix.
+    #[error("scalability mode {0:?} has an unrecognized suffix after its layer counts")]
+    UnrecognizedSuffix(String),
+}
+
+/// Parsed form of an SVC scalability mode string (e.g. `"L1T3"`), as carried by
+/// [`RtpEncodingParameters::scalability_mode`]. Mirrors the notation WebRTC's scalability-mode
+/// handling uses: `L`/`S` for the inter-layer dependency structure, a spatial layer count, `T`,
+/// a temporal layer count, an optional `_KEY` suffix for K-SVC, and an optional `h` (before or
+/// after `_KEY`) for a 1.5x rather than 2x resolution ratio between spatial layers.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ScalabilityMode {
+    /// Whether upper spatial layers fully depend on lower ones (`L`) or are simulcast (`S`).
+    pub structure: ScalabilityModeStructure,
+    /// Number of spatial layers.
+    pub spatial_layers: u8,
+    /// Number of temporal layers.
+    pub temporal_layers: u8,
+    /// K-SVC: upper spatial layers only depend on lower-layer key frames, marked by the `_KEY`
+    /// suffix.
+    pub ksvc: bool,
+    /// Resolution ratio between consecutive spatial layers.
+    pub resolution_ratio: ScalabilityModeResolutionRatio,
+}
+
+impl fmt::Display for ScalabilityMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let structure = match self.structure {
+            ScalabilityModeStructure::Layered => 'L',
+            ScalabilityModeStructure::Simulcast => 'S',
+        };
+        write!(f, "{structure}{}T{}", self.spatial_layers, self.temporal_layers)?;
+        if self.ksvc {
+            write!(f, "_KEY")?;
+        }
+        if self.resolution_ratio == ScalabilityModeResolutionRatio::OneAndAHalf {
+            write!(f, "h")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ScalabilityMode {
+    type Err = ScalabilityModeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let structure = match chars.next() {
+            Some('L') => ScalabilityModeStructure::Layered,
+            Some('S') => ScalabilityModeStructure::Simulcast,
+            _ => return Err(ScalabilityModeParseError::UnknownStructure(s.to_string())),
+        };
+        let rest = chars.as_str();
+
+        let t_index = rest.find('T').ok_or_else(|| {
+            ScalabilityModeParseError::MissingTemporalSeparator(s.to_string())
+        })?;
+
+        let spatial_layers: u8 = rest[..t_index]
+            .parse()
+            .map_err(|_err| ScalabilityModeParseError::MalformedLayerCount(s.to_string()))?;
+
+        let after_t = &rest[t_index + 1..];
+        let digit_count = after_t.chars().take_while(char::is_ascii_digit).count();
+        if digit_count == 0 {
+            return Err(ScalabilityModeParseError::MalformedLayerCount(s.to_string()));
+        }
+        let temporal_layers: u8 = after_t[..digit_count]
+            .parse()
+            .map_err(|_err| ScalabilityModeParseError::MalformedLayerCount(s.to_string()))?;
+
+        if spatial_layers == 0 || temporal_layers == 0 {
+            return Err(ScalabilityModeParseError::ZeroLayerCount(s.to_string()));
+        }
+        if spatial_layers > SCALABILITY_MODE_MAX_SPATIAL_LAYERS
+            || temporal_layers > SCALABILITY_MODE_MAX_TEMPORAL_LAYERS
+        {
+            return Err(ScalabilityModeParseError::TooManyLayers(s.to_string()));
+        }
+
+        let (ksvc, resolution_ratio) = match &after_t[digit_count..] {
+            "" => (false, ScalabilityModeResolutionRatio::Two),
+            "h" => (false, ScalabilityModeResolutionRatio::OneAndAHalf),
+            "_KEY" => (true, ScalabilityModeResolutionRatio::Two),
+            "_KEYh" | "h_KEY" => (true, ScalabilityModeResolutionRatio::OneAndAHalf),
+            _ => return Err(ScalabilityModeParseError::UnrecognizedSuffix(s.to_string())),
+        };
+
+        Ok(ScalabilityMode {
+            structure,
+            spatial_layers,
+            temporal_layers,
+            ksvc,
+            resolution_ratio,
+        })
+    }
+}
+
+impl TryFrom<String> for ScalabilityMode {
+    type Error = ScalabilityModeParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<ScalabilityMode> for String {
+    fn from(mode: ScalabilityMode) -> Self {
+        mode.to_string()
+    }
+}
+
+#[cfg(test)]
+mod scalability_mode_tests {
+    use super::*;
+
+    #[test]
+    fn parses_l1t3() {
+        let mode: ScalabilityMode = "L1T3".parse().unwrap();
+        assert_eq!(mode.structure, ScalabilityModeStructure::Layered);
+        assert_eq!(mode.spatial_layers, 1);
+        assert_eq!(mode.temporal_layers, 3);
+        assert!(!mode.ksvc);
+        assert_eq!(mode.resolution_ratio, ScalabilityModeResolutionRatio::Two);
+        assert_eq!(mode.to_string(), "L1T3");
+    }
+
+    #[test]
+    fn parses_l3t3_key() {
+        let mode: ScalabilityMode = "L3T3_KEY".parse().unwrap();
+        assert_eq!(mode.structure, ScalabilityModeStructure::Layered);
+        assert_eq!(mode.spatial_layers, 3);
+        assert_eq!(mode.temporal_layers, 3);
+        assert!(mode.ksvc);
+        assert_eq!(mode.resolution_ratio, ScalabilityModeResolutionRatio::Two);
+        assert_eq!(mode.to_string(), "L3T3_KEY");
+    }
+
+    #[test]
+    fn parses_s3t3() {
+        let mode: ScalabilityMode = "S3T3".parse().unwrap();
+        assert_eq!(mode.structure, ScalabilityModeStructure::Simulcast);
+        assert_eq!(mode.spatial_layers, 3);
+        assert_eq!(mode.temporal_layers, 3);
+        assert!(!mode.ksvc);
+        assert_eq!(mode.to_string(), "S3T3");
+    }
+
+    #[test]
+    fn parses_half_resolution_ratio_before_and_after_key() {
+        let before: ScalabilityMode = "L2T2h_KEY".parse().unwrap();
+        let after: ScalabilityMode = "L2T2_KEYh".parse().unwrap();
+        for mode in [before, after] {
+            assert!(mode.ksvc);
+            assert_eq!(
+                mode.resolution_ratio,
+                ScalabilityModeResolutionRatio::OneAndAHalf
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_structure() {
+        assert_eq!(
+            "X1T3".parse::<ScalabilityMode>(),
+            Err(ScalabilityModeParseError::UnknownStructure("X1T3".to_string())),
+        );
+    }
+
+    #[test]
+    fn rejects_missing_temporal_separator() {
+        assert_eq!(
+            "L1".parse::<ScalabilityMode>(),
+            Err(ScalabilityModeParseError::MissingTemporalSeparator(
+                "L1".to_string()
+            )),
+        );
+    }
+
+    #[test]
+    fn rejects_zero_layer_count() {
+        assert_eq!(
+            "L0T3".parse::<ScalabilityMode>(),
+            Err(ScalabilityModeParseError::ZeroLayerCount("L0T3".to_string())),
+        );
+    }
+
+    #[test]
+    fn rejects_too_many_layers() {
+        assert_eq!(
+            "L4T3".parse::<ScalabilityMode>(),
+            Err(ScalabilityModeParseError::TooManyLayers("L4T3".to_string())),
+        );
+    }
+
+    #[test]
+    fn rejects_unrecognized_suffix() {
+        assert_eq!(
+            "L1T3_FOO".parse::<ScalabilityMode>(),
+            Err(ScalabilityModeParseError::UnrecognizedSuffix(
+                "L1T3_FOO".to_string()
+            )),
+        );
+    }
+}
+
 /// Provides information relating to an encoding, which represents a media RTP
 /// stream and its associated RTX stream (if any).
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -232,14 +711,45 @@ pub struct RtpEncodingParameters {
     /// Default false.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dtx: Option<bool>,
-    // TODO: Maybe enum?
     /// Number of spatial and temporal layers in the RTP stream (e.g. 'L1T3'). See webrtc-svc.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scalability_mode: Option<String>,
+    pub scalability_mode: Option<ScalabilityMode>,
+    /// Whether this encoding is sent/received. Allows pausing/resuming a simulcast layer without
+    /// renegotiating.
+    /// Default true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// Fractional resolution downscale applied to the encoded frame, relative to the source. A
+    /// value of `2.0` halves both width and height. Ignored if [`Self::requested_resolution`] is
+    /// also set, since an absolute target resolution takes precedence over a relative one.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scale_resolution_down_by: Option<f64>,
+    /// Absolute target resolution for the encoded frame, taking precedence over
+    /// [`Self::scale_resolution_down_by`] when both are set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_resolution: Option<Resolution>,
+    /// Maximum bitrate, in bits per second, this encoding is allowed to use.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_bitrate: Option<u32>,
+    /// Maximum framerate, in frames per second, this encoding is allowed to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_framerate: Option<f64>,
+    /// How to degrade this encoding's quality under bandwidth/CPU pressure. Defaults to
+    /// [`DegradationPreference::Balanced`] when absent, preserving prior behavior.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub degradation_preference: Option<DegradationPreference>,
+    /// Audio only. Lets the sender grow or shrink the Opus packet time under congestion, trading
+    /// latency for packet overhead, instead of sending fixed-size packets at [`Self::ptime`].
+    /// Ignored for video encodings. Default false, preserving prior behavior.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adaptive_ptime: Option<bool>,
+    /// Audio only. Preferred packet time, in milliseconds, ignored for video encodings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ptime: Option<u32>,
+    /// Audio only. Maximum packet time [`Self::adaptive_ptime`] may grow to, in milliseconds,
+    /// ignored for video encodings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ptime: Option<u32>,
 }
 
 // TODO: supportedRtpCapabilities.ts file and generally update TypeScript references
@@ -0,0 +1,411 @@
+//! SDP offer/answer generation and parsing for a [`WebRtcTransport`](crate::webrtc_transport::WebRtcTransport),
+//! converting between SDP and this crate's native ICE/DTLS/RTP/SCTP types — the reusable core any
+//! signaling integration (including [`crate::whip`]) needs instead of hand-rolling SDP. Not yet
+//! declared as `mod sdp;` anywhere — see `CONTRIBUTING.md` for why.
+//!
+//! This module is written against `crate::data_structures::{IceParameters, IceCandidate,
+//! DtlsParameters, TransportProtocol, IceCandidateType, IceCandidateTcpType}` and
+//! `crate::sctp_parameters::SctpParameters`, the shapes `rust/tests/webrtc_transport.rs` already
+//! exercises (`ice_parameters().ice_lite`, `ice_candidates()[n].{ip,protocol,r#type,tcp_type,
+//! priority}`, `dtls_parameters().role`, `sctp_parameters()` with `{port,os,mis,
+//! max_message_size}`). Neither `data_structures.rs` nor `sctp_parameters.rs` exists in this
+//! crate snapshot yet (the same class of gap as `crate::router::Router`/
+//! `crate::webrtc_transport::WebRtcTransport` elsewhere), so for now this file assumes
+//! `IceParameters` additionally carries `username_fragment: String`/`password: String`, and
+//! `DtlsParameters` carries `fingerprints: Vec<DtlsFingerprint>` where `DtlsFingerprint` has
+//! `algorithm: String`/`value: String` — the fields an `a=ice-ufrag`/`a=ice-pwd`/`a=fingerprint`
+//! line needs. Once those modules land with that shape, [`build_answer`]/[`parse_offer`] below
+//! should compile and work as written rather than needing to be redesigned.
+
+use crate::data_structures::{
+    DtlsParameters, IceCandidate, IceCandidateTcpType, IceCandidateType, IceParameters,
+    TransportProtocol,
+};
+use crate::rtp_parameters::{RtpCodecParameters, RtpParameters};
+use crate::sctp_parameters::SctpParameters;
+use std::fmt::Write as _;
+use thiserror::Error;
+
+fn protocol_str(protocol: &TransportProtocol) -> &'static str {
+    match protocol {
+        TransportProtocol::Udp => "udp",
+        TransportProtocol::Tcp => "tcp",
+    }
+}
+
+fn candidate_type_str(candidate_type: &IceCandidateType) -> &'static str {
+    match candidate_type {
+        IceCandidateType::Host => "host",
+        IceCandidateType::Srflx => "srflx",
+        IceCandidateType::Prflx => "prflx",
+        IceCandidateType::Relay => "relay",
+    }
+}
+
+fn tcp_type_str(tcp_type: &IceCandidateTcpType) -> &'static str {
+    match tcp_type {
+        IceCandidateTcpType::Active => "active",
+        IceCandidateTcpType::Passive => "passive",
+        IceCandidateTcpType::SimultaneousOpen => "so",
+    }
+}
+
+/// One SDP media section ("m-line"): a media kind, its `mid`, and the negotiated
+/// [`RtpParameters`] to advertise for it.
+#[derive(Debug, Clone)]
+pub struct MediaSection<'a> {
+    /// `"audio"` or `"video"`, written verbatim into the m-line.
+    pub media_type: &'static str,
+    /// The MID RTP extension value identifying this section, shared with
+    /// [`RtpParameters::mid`].
+    pub mid: &'a str,
+    /// The codecs (and, for data, nothing — data channels go through [`build_answer`]'s
+    /// `sctp_parameters` instead) to advertise on this section.
+    pub rtp_parameters: &'a RtpParameters,
+}
+
+/// Error returned by [`parse_offer`] when a required SDP line is missing or malformed.
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum SdpError {
+    /// A line this module requires to build `connect()`/`produce()` parameters wasn't present.
+    #[error("SDP is missing a required line: {0}")]
+    MissingLine(&'static str),
+    /// A present line didn't match the expected syntax.
+    #[error("could not parse SDP line {line:?}: {reason}")]
+    MalformedLine {
+        /// The offending line, verbatim.
+        line: String,
+        /// What about it didn't parse.
+        reason: &'static str,
+    },
+}
+
+/// The subset of an SDP offer [`parse_offer`] extracts: enough to call `transport.connect()` and
+/// one `transport.produce()` per media section.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedOffer {
+    /// The offer's ICE username fragment (`a=ice-ufrag`).
+    pub ice_username_fragment: String,
+    /// The offer's ICE password (`a=ice-pwd`).
+    pub ice_password: String,
+    /// The offer's DTLS fingerprint algorithm (e.g. `"sha-256"`) and hex value, from
+    /// `a=fingerprint`.
+    pub dtls_fingerprint: (String, String),
+    /// One entry per `m=audio`/`m=video` section, in SDP order: the media type and the codecs
+    /// offered on it, one per `a=rtpmap` line (MIME type, payload type, clock rate, channels).
+    /// `a=fmtp`/`a=rtcp-fb` lines are not read: each codec's `parameters` is empty and its
+    /// `rtcp_feedback` is `None`.
+    pub media_sections: Vec<(String, Vec<RtpCodecParameters>)>,
+}
+
+fn write_ice_and_dtls_lines(
+    sdp: &mut String,
+    ice_parameters: &IceParameters,
+    ice_candidates: &[IceCandidate],
+    dtls_parameters: &DtlsParameters,
+) {
+    let _ = writeln!(sdp, "a=ice-ufrag:{}", ice_parameters.username_fragment);
+    let _ = writeln!(sdp, "a=ice-pwd:{}", ice_parameters.password);
+    if ice_parameters.ice_lite.unwrap_or(false) {
+        let _ = writeln!(sdp, "a=ice-lite");
+    }
+    for fingerprint in &dtls_parameters.fingerprints {
+        let _ = writeln!(
+            sdp,
+            "a=fingerprint:{} {}",
+            fingerprint.algorithm, fingerprint.value
+        );
+    }
+    let _ = writeln!(sdp, "a=setup:{}", dtls_setup_attribute(dtls_parameters));
+    for (index, candidate) in ice_candidates.iter().enumerate() {
+        let _ = writeln!(
+            sdp,
+            "a=candidate:{} 1 {} {} {} {} typ {}{}",
+            index + 1,
+            protocol_str(&candidate.protocol),
+            candidate.priority,
+            candidate.ip,
+            candidate.port,
+            candidate_type_str(&candidate.r#type),
+            candidate
+                .tcp_type
+                .as_ref()
+                .map(|tcp_type| format!(" tcptype {}", tcp_type_str(tcp_type)))
+                .unwrap_or_default(),
+        );
+    }
+}
+
+fn dtls_setup_attribute(dtls_parameters: &DtlsParameters) -> &'static str {
+    // mediasoup always plays the DTLS server role from the remote endpoint's point of view,
+    // regardless of `DtlsParameters::role` (which governs mediasoup's *own* negotiated role).
+    let _ = dtls_parameters;
+    "passive"
+}
+
+/// Builds a single m-line's `a=rtpmap`/`a=fmtp`/`a=rtcp-fb` attribute lines for `rtp_parameters`.
+fn write_codec_lines(sdp: &mut String, rtp_parameters: &RtpParameters) {
+    for codec in &rtp_parameters.codecs {
+        let mime_subtype = codec
+            .mime_type
+            .rsplit('/')
+            .next()
+            .unwrap_or(&codec.mime_type);
+        match codec.channels {
+            Some(channels) if channels > 1 => {
+                let _ = writeln!(
+                    sdp,
+                    "a=rtpmap:{} {}/{}/{}",
+                    codec.payload_type, mime_subtype, codec.clock_rate, channels
+                );
+            }
+            _ => {
+                let _ = writeln!(
+                    sdp,
+                    "a=rtpmap:{} {}/{}",
+                    codec.payload_type, mime_subtype, codec.clock_rate
+                );
+            }
+        }
+
+        if !codec.parameters.is_empty() {
+            let mut params: Vec<_> = codec.parameters.iter().collect();
+            params.sort_by(|(a, _), (b, _)| a.cmp(b));
+            let fmtp = params
+                .into_iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect::<Vec<_>>()
+                .join(";");
+            let _ = writeln!(sdp, "a=fmtp:{} {}", codec.payload_type, fmtp);
+        }
+
+        for feedback in codec.rtcp_feedback.iter().flatten().copied() {
+            let (r#type, parameter) = feedback.wire_type_and_parameter();
+            match parameter {
+                Some(parameter) => {
+                    let _ = writeln!(
+                        sdp,
+                        "a=rtcp-fb:{} {} {}",
+                        codec.payload_type, r#type, parameter
+                    );
+                }
+                None => {
+                    let _ = writeln!(sdp, "a=rtcp-fb:{} {}", codec.payload_type, r#type);
+                }
+            }
+        }
+    }
+}
+
+/// Builds a unified-plan SDP answer for `media_sections`, all sharing the single ICE/DTLS
+/// transport described by `ice_parameters`/`ice_candidates`/`dtls_parameters` (mediasoup bundles
+/// every m-line onto one transport), plus a final `application` m-line with `a=sctp-port` if
+/// `sctp_parameters` is given (a data channel was negotiated).
+pub fn build_answer(
+    ice_parameters: &IceParameters,
+    ice_candidates: &[IceCandidate],
+    dtls_parameters: &DtlsParameters,
+    sctp_parameters: Option<&SctpParameters>,
+    media_sections: &[MediaSection<'_>],
+) -> String {
+    let mut sdp = String::new();
+    let _ = writeln!(sdp, "v=0");
+    let _ = writeln!(sdp, "o=- 0 0 IN IP4 0.0.0.0");
+    let _ = writeln!(sdp, "s=-");
+    let _ = writeln!(sdp, "t=0 0");
+
+    let mut mids: Vec<&str> = media_sections.iter().map(|section| section.mid).collect();
+    if sctp_parameters.is_some() {
+        mids.push("data");
+    }
+    let _ = writeln!(sdp, "a=group:BUNDLE {}", mids.join(" "));
+
+    for section in media_sections {
+        let payload_types = section
+            .rtp_parameters
+            .codecs
+            .iter()
+            .map(|codec| codec.payload_type.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let _ = writeln!(
+            sdp,
+            "m={} 9 UDP/TLS/RTP/SAVPF {}",
+            section.media_type, payload_types
+        );
+        let _ = writeln!(sdp, "c=IN IP4 0.0.0.0");
+        let _ = writeln!(sdp, "a=mid:{}", section.mid);
+        let _ = writeln!(sdp, "a=recvonly");
+        write_ice_and_dtls_lines(&mut sdp, ice_parameters, ice_candidates, dtls_parameters);
+        write_codec_lines(&mut sdp, section.rtp_parameters);
+    }
+
+    if let Some(sctp_parameters) = sctp_parameters {
+        let _ = writeln!(sdp, "m=application 9 UDP/DTLS/SCTP webrtc-datachannel");
+        let _ = writeln!(sdp, "c=IN IP4 0.0.0.0");
+        let _ = writeln!(sdp, "a=mid:data");
+        write_ice_and_dtls_lines(&mut sdp, ice_parameters, ice_candidates, dtls_parameters);
+        let _ = writeln!(sdp, "a=sctp-port:{}", sctp_parameters.port);
+        let _ = writeln!(
+            sdp,
+            "a=max-message-size:{}",
+            sctp_parameters.max_message_size
+        );
+    }
+
+    sdp
+}
+
+fn find_attribute<'a>(lines: &'a [&'a str], prefix: &str) -> Option<&'a str> {
+    lines
+        .iter()
+        .find_map(|line| line.strip_prefix(prefix).map(str::trim))
+}
+
+/// Parses the ICE/DTLS/codec parameters [`Transport::connect`](crate::transport::Transport)
+/// and one `produce()` call per media section need out of a remote SDP offer.
+///
+/// This is a minimal, dependency-free line scanner (this crate has no SDP-parsing crate as a
+/// dependency), not a general-purpose SDP parser: it looks for the first `a=ice-ufrag`/
+/// `a=ice-pwd`/`a=fingerprint` lines (mediasoup bundles every m-line onto one ICE/DTLS transport,
+/// so the first occurrence applies to all of them) and, per `m=audio`/`m=video` section, every
+/// `a=rtpmap` line in it. `a=fmtp`/`a=rtcp-fb` lines and the payload type list on the `m=` line
+/// itself are not read.
+pub fn parse_offer(sdp: &str) -> Result<ParsedOffer, SdpError> {
+    let lines: Vec<&str> = sdp.lines().map(str::trim).collect();
+
+    let ice_username_fragment = find_attribute(&lines, "a=ice-ufrag:")
+        .ok_or(SdpError::MissingLine("a=ice-ufrag"))?
+        .to_string();
+    let ice_password = find_attribute(&lines, "a=ice-pwd:")
+        .ok_or(SdpError::MissingLine("a=ice-pwd"))?
+        .to_string();
+    let fingerprint_line =
+        find_attribute(&lines, "a=fingerprint:").ok_or(SdpError::MissingLine("a=fingerprint"))?;
+    let dtls_fingerprint = fingerprint_line
+        .split_once(' ')
+        .map(|(algorithm, value)| (algorithm.to_string(), value.to_string()))
+        .ok_or_else(|| SdpError::MalformedLine {
+            line: format!("a=fingerprint:{fingerprint_line}"),
+            reason: "expected '<algorithm> <value>'",
+        })?;
+
+    let mut media_sections = Vec::new();
+    let mut current: Option<(String, Vec<RtpCodecParameters>)> = None;
+
+    for line in &lines {
+        if let Some(rest) = line.strip_prefix("m=") {
+            if let Some(section) = current.take() {
+                media_sections.push(section);
+            }
+            let media_type = rest.split_whitespace().next().unwrap_or("").to_string();
+            if media_type == "audio" || media_type == "video" {
+                current = Some((media_type, Vec::new()));
+            }
+        } else if let Some(rest) = line.strip_prefix("a=rtpmap:") {
+            if let Some((media_type, codecs)) = current.as_mut() {
+                let (payload_type, rest) =
+                    rest.split_once(' ').ok_or_else(|| SdpError::MalformedLine {
+                        line: (*line).to_string(),
+                        reason: "expected '<payload type> <encoding>'",
+                    })?;
+                let payload_type: u8 = payload_type.parse().map_err(|_err| SdpError::MalformedLine {
+                    line: (*line).to_string(),
+                    reason: "payload type is not a valid u8",
+                })?;
+                let mut parts = rest.split('/');
+                let subtype = parts.next().unwrap_or("");
+                let clock_rate: u32 = parts
+                    .next()
+                    .and_then(|rate| rate.parse().ok())
+                    .ok_or_else(|| SdpError::MalformedLine {
+                        line: (*line).to_string(),
+                        reason: "missing or invalid clock rate",
+                    })?;
+                let channels: Option<u8> = parts.next().and_then(|channels| channels.parse().ok());
+                codecs.push(RtpCodecParameters {
+                    mime_type: format!("{media_type}/{subtype}"),
+                    payload_type,
+                    clock_rate,
+                    channels,
+                    parameters: Default::default(),
+                    rtcp_feedback: None,
+                });
+            }
+        }
+    }
+    if let Some(section) = current.take() {
+        media_sections.push(section);
+    }
+
+    Ok(ParsedOffer {
+        ice_username_fragment,
+        ice_password,
+        dtls_fingerprint,
+        media_sections,
+    })
+}
+
+#[cfg(test)]
+mod parse_offer_tests {
+    use super::*;
+
+    const OFFER: &str = "\
+v=0\r
+o=- 0 0 IN IP4 0.0.0.0\r
+s=-\r
+t=0 0\r
+a=group:BUNDLE 0 1\r
+a=ice-ufrag:abcd\r
+a=ice-pwd:abcdefghijklmnopqrstuvwx\r
+a=fingerprint:sha-256 AB:CD:EF\r
+m=audio 9 UDP/TLS/RTP/SAVPF 111\r
+a=mid:0\r
+a=rtpmap:111 opus/48000/2\r
+m=video 9 UDP/TLS/RTP/SAVPF 96\r
+a=mid:1\r
+a=rtpmap:96 VP8/90000\r
+";
+
+    #[test]
+    fn parses_ice_and_dtls_lines() {
+        let parsed = parse_offer(OFFER).unwrap();
+        assert_eq!(parsed.ice_username_fragment, "abcd");
+        assert_eq!(parsed.ice_password, "abcdefghijklmnopqrstuvwx");
+        assert_eq!(
+            parsed.dtls_fingerprint,
+            ("sha-256".to_string(), "AB:CD:EF".to_string())
+        );
+    }
+
+    #[test]
+    fn parses_one_codec_per_media_section() {
+        let parsed = parse_offer(OFFER).unwrap();
+        assert_eq!(parsed.media_sections.len(), 2);
+
+        let (audio_type, audio_codecs) = &parsed.media_sections[0];
+        assert_eq!(audio_type, "audio");
+        assert_eq!(audio_codecs.len(), 1);
+        assert_eq!(audio_codecs[0].mime_type, "audio/opus");
+        assert_eq!(audio_codecs[0].payload_type, 111);
+        assert_eq!(audio_codecs[0].clock_rate, 48000);
+        assert_eq!(audio_codecs[0].channels, Some(2));
+
+        let (video_type, video_codecs) = &parsed.media_sections[1];
+        assert_eq!(video_type, "video");
+        assert_eq!(video_codecs.len(), 1);
+        assert_eq!(video_codecs[0].mime_type, "video/VP8");
+        assert_eq!(video_codecs[0].payload_type, 96);
+        assert_eq!(video_codecs[0].clock_rate, 90000);
+        assert_eq!(video_codecs[0].channels, None);
+    }
+
+    #[test]
+    fn missing_ice_ufrag_is_an_error() {
+        let sdp = "v=0\r\na=ice-pwd:x\r\na=fingerprint:sha-256 AB\r\n";
+        assert_eq!(
+            parse_offer(sdp),
+            Err(SdpError::MissingLine("a=ice-ufrag"))
+        );
+    }
+}
@@ -0,0 +1,215 @@
+//! Pluggable room signalling, so a client can join, negotiate, and tear down a session without
+//! each application reinventing the negotiation state machine otherwise duplicated by hand
+//! against `Router`/`WebRtcTransport` (the flow partially shown in `tests/webrtc_transport.rs`).
+//! Analogous to the multiple signaller backends (Janus VideoRoom, LiveKit, AWS KVS, WHIP) the gst
+//! `webrtcsink` element selects between at runtime: [`Signaller`] is the trait third parties
+//! implement for their own wire protocol, while [`crate::whip::WhipHandler`] covers WHIP with a
+//! separate trait of its own (over plain HTTP, per the WHIP draft). This adds [`RoomSignaller`], a
+//! [`Signaller`] implementation over a WebSocket JSON "room" protocol: a client sends [`RoomMessage::Join`], gets back the
+//! room's ICE-lite candidates and DTLS fingerprint in [`RoomMessage::RoomJoined`], sends its own
+//! offer as [`RoomMessage::Offer`] and trickles candidates as [`RoomMessage::IceCandidate`], to
+//! end up with producers/consumers on the room's transport.
+//!
+//! Kept as a plain synchronous trait, matching this crate's other pluggable-backend traits
+//! ([`ConnectorStorage`](crate::connector::storage::ConnectorStorage),
+//! [`WorkerMetricsRecorder`](crate::worker::WorkerMetricsRecorder)), rather than the `async fn`
+//! methods a Janus/LiveKit-style signaller conceptually wants, since this crate has no
+//! `async_trait` dependency to make trait methods `async fn` without nightly support; a genuinely
+//! async implementation has to bridge that gap itself (e.g. via `futures_lite::future::block_on`
+//! around its own `Router` calls).
+//!
+//! Like [`crate::whip`], what's genuinely implemented here is the protocol-facing half:
+//! [`RoomMessage`] (the WebSocket JSON wire format) and [`RoomSignaller`]'s resource-id/error
+//! bookkeeping. Actually creating a transport and producers/consumers per message needs a
+//! `Router`/`WebRtcTransport` pair with real `create_*`/`connect`/`produce`/`consume` methods,
+//! which (as in `whip.rs`) don't exist as implementation files in this crate snapshot yet;
+//! [`RoomSignaller`]'s [`Signaller`] methods document the call sequence and return
+//! [`SignallerError::UnavailableDependency`] rather than faking success.
+
+use crate::uuid_based_wrapper_type;
+use crate::whip::IceServer;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+uuid_based_wrapper_type!(
+    /// Identifies one client's joined room session, handed back in
+    /// [`RoomMessage::RoomJoined`] so a later message on the same connection (or a reconnect) can
+    /// be routed back to the right session.
+    RoomSessionId
+);
+
+/// The WebSocket JSON wire protocol [`RoomSignaller`] speaks, one variant per message `type`,
+/// with the rest of the message nested under `data` — matching the tagging convention this
+/// crate's worker-notification enums already use.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase", content = "data")]
+pub enum RoomMessage {
+    /// Client -> server: join `room`.
+    Join {
+        /// Name of the room to join.
+        room: String,
+    },
+    /// Server -> client, in response to [`Self::Join`]: the room's ICE-lite candidates (each
+    /// formatted as an `a=candidate` SDP attribute line, per
+    /// [RFC 8839](https://datatracker.ietf.org/doc/html/rfc8839)) and DTLS certificate
+    /// fingerprint, so the client can complete its offer before sending it as [`Self::Offer`].
+    RoomJoined {
+        /// This session's id, echoed back on later messages if the wire protocol needs it.
+        session_id: RoomSessionId,
+        /// `a=candidate` attribute lines for the room transport's ICE-lite candidates.
+        ice_candidates: Vec<String>,
+        /// The room transport's ICE username fragment.
+        ice_username_fragment: String,
+        /// The room transport's ICE password.
+        ice_password: String,
+        /// Certificate fingerprint algorithm, e.g. `"sha-256"`.
+        dtls_fingerprint_algorithm: String,
+        /// Certificate fingerprint value, colon-hex-encoded.
+        dtls_fingerprint_value: String,
+    },
+    /// Client -> server: the client's SDP offer.
+    Offer {
+        /// The offer's SDP.
+        sdp: String,
+    },
+    /// Server -> client: this server's SDP answer to a prior [`Self::Offer`].
+    Answer {
+        /// The answer's SDP.
+        sdp: String,
+    },
+    /// Either direction: a trickled ICE candidate.
+    IceCandidate {
+        /// `mid` of the media section this candidate belongs to.
+        mid: String,
+        /// `a=candidate` SDP attribute line.
+        candidate: String,
+    },
+    /// Either direction: leave the room, tearing down its transport(s).
+    Close,
+    /// Server -> client: something went wrong processing the last message (mirrors a
+    /// [`SignallerError`]).
+    Error {
+        /// Human-readable description of what went wrong.
+        message: String,
+    },
+}
+
+/// Error returned by a [`Signaller`] method.
+#[derive(Debug, Error)]
+pub enum SignallerError {
+    /// The offer's SDP couldn't be parsed, or an answer's SDP couldn't be generated.
+    #[error("failed to process session description: {0}")]
+    SdpError(String),
+    /// An ICE candidate couldn't be applied (e.g. it named an unknown `mid`).
+    #[error("failed to apply ICE candidate: {0}")]
+    IceCandidateError(String),
+    /// This method needs a dependency (a `Router`/`WebRtcTransport` implementation) that doesn't
+    /// exist in this crate snapshot yet.
+    #[error("signalling is unavailable: {0}")]
+    UnavailableDependency(&'static str),
+}
+
+/// Negotiates a session on behalf of one client, over whatever wire protocol an implementation
+/// speaks. An application constructs one per connection (or per room member), feeding it
+/// messages as they arrive off that connection's transport (WebSocket, `DataChannel`, ...) and
+/// writing out whatever [`Offer`](Self::offer)/[`answer`](Self::answer) return.
+pub trait Signaller {
+    /// Handles a client's SDP offer, returning the SDP answer to send back.
+    fn offer(&self, offer_sdp: &str) -> Result<String, SignallerError>;
+
+    /// Returns this signaller's own current answer, for protocols with a server-driven
+    /// subscribe/join step that hands out an SDP answer before seeing a client offer (unlike
+    /// [`offer`](Self::offer), there's no fresh offer to answer here).
+    fn answer(&self) -> Result<String, SignallerError>;
+
+    /// Delivers a remote ICE candidate trickled in after the initial offer/answer.
+    fn on_ice_candidate(&self, mid: &str, candidate: &str) -> Result<(), SignallerError>;
+
+    /// Tears down the transport(s) this signaller negotiated.
+    fn close(&self) -> Result<(), SignallerError>;
+}
+
+/// [`Signaller`] implementation for [`RoomMessage`]'s WebSocket JSON room protocol: a client
+/// joins a named room and negotiates a single transport carrying all of that room's
+/// producers/consumers.
+pub struct RoomSignaller {
+    session_id: RoomSessionId,
+    room: String,
+    /// STUN/TURN servers advertised to joining clients, reusing [`IceServer`] from
+    /// [`crate::whip`] rather than defining an equivalent type twice.
+    ice_servers: Vec<IceServer>,
+}
+
+impl RoomSignaller {
+    /// Creates a signaller for a client joining `room`, advertising `ice_servers` to it.
+    pub fn new(room: impl Into<String>, ice_servers: Vec<IceServer>) -> Self {
+        Self {
+            session_id: RoomSessionId::new(),
+            room: room.into(),
+            ice_servers,
+        }
+    }
+
+    /// This session's id, to embed in [`RoomMessage::RoomJoined`].
+    pub fn session_id(&self) -> RoomSessionId {
+        self.session_id
+    }
+
+    /// Room this signaller was constructed for.
+    pub fn room(&self) -> &str {
+        &self.room
+    }
+
+    /// Builds this session's [`RoomMessage::RoomJoined`] reply. A working implementation would
+    /// source the ICE/DTLS fields from a real `WebRtcTransport`'s parameters (see
+    /// [`crate::sdp::build_answer`]'s equivalent assumptions about those fields' shape); absent
+    /// that transport type in this crate snapshot, this always errors.
+    pub fn room_joined(&self) -> Result<RoomMessage, SignallerError> {
+        let _ = &self.ice_servers;
+        Err(SignallerError::UnavailableDependency(
+            "building a RoomJoined reply needs a Router/WebRtcTransport implementation, which \
+             doesn't exist in this crate snapshot",
+        ))
+    }
+}
+
+impl Signaller for RoomSignaller {
+    /// A working implementation would, roughly:
+    /// 1. `router.create_webrtc_transport(...)` to get this room's `WebRtcTransport`, if not
+    ///    already created for an earlier room member.
+    /// 2. Parse `offer_sdp` for ICE ufrag/pwd, DTLS fingerprint, and per-media `RtpParameters`
+    ///    (see [`crate::sdp::parse_offer`]).
+    /// 3. `transport.connect(...)` with the offer's ICE/DTLS parameters.
+    /// 4. `transport.produce(...)`/`transport.consume(...)` per the room's membership.
+    /// 5. Generate an SDP answer carrying the transport's own ICE/DTLS parameters (see
+    ///    [`crate::sdp::build_answer`]).
+    fn offer(&self, offer_sdp: &str) -> Result<String, SignallerError> {
+        let _ = offer_sdp;
+        Err(SignallerError::UnavailableDependency(
+            "negotiating an offer needs a Router/WebRtcTransport implementation, which doesn't \
+             exist in this crate snapshot",
+        ))
+    }
+
+    fn answer(&self) -> Result<String, SignallerError> {
+        Err(SignallerError::UnavailableDependency(
+            "producing an answer needs a Router/WebRtcTransport implementation, which doesn't \
+             exist in this crate snapshot",
+        ))
+    }
+
+    fn on_ice_candidate(&self, mid: &str, candidate: &str) -> Result<(), SignallerError> {
+        let _ = (mid, candidate);
+        Err(SignallerError::UnavailableDependency(
+            "applying a trickled ICE candidate needs a WebRtcTransport implementation, which \
+             doesn't exist in this crate snapshot",
+        ))
+    }
+
+    fn close(&self) -> Result<(), SignallerError> {
+        Err(SignallerError::UnavailableDependency(
+            "closing a room session needs a WebRtcTransport implementation, which doesn't exist \
+             in this crate snapshot",
+        ))
+    }
+}
@@ -1,9 +1,9 @@
 //! RTP capabilities supported by Mediasoup.
 
 use crate::rtp_parameters::{
-    MediaKind, MimeTypeAudio, MimeTypeVideo, RtcpFeedback, RtpCapabilities, RtpCodecCapability,
-    RtpCodecParametersParameters, RtpHeaderExtension, RtpHeaderExtensionDirection,
-    RtpHeaderExtensionUri,
+    FecMechanism, MediaKind, MimeTypeAudio, MimeTypeVideo, RtcpFeedback, RtpCapabilities,
+    RtpCodecCapability, RtpCodecParametersParameters, RtpHeaderExtension,
+    RtpHeaderExtensionDirection, RtpHeaderExtensionUri,
 };
 use std::num::{NonZeroU32, NonZeroU8};
 
@@ -173,6 +173,7 @@ pub fn get_supported_rtp_capabilities() -> RtpCapabilities {
                     RtcpFeedback::CcmFir,
                     RtcpFeedback::GoogRemb,
                     RtcpFeedback::TransportCC,
+                    RtcpFeedback::Lntf,
                 ],
             },
             RtpCodecCapability::Video {
@@ -180,6 +181,24 @@ pub fn get_supported_rtp_capabilities() -> RtpCapabilities {
                 preferred_payload_type: None,
                 clock_rate: NonZeroU32::new(90000).unwrap(),
                 parameters: RtpCodecParametersParameters::new(),
+                rtcp_feedback: vec![
+                    RtcpFeedback::Nack,
+                    RtcpFeedback::NackPli,
+                    RtcpFeedback::CcmFir,
+                    RtcpFeedback::GoogRemb,
+                    RtcpFeedback::TransportCC,
+                    RtcpFeedback::Lntf,
+                ],
+            },
+            RtpCodecCapability::Video {
+                mime_type: MimeTypeVideo::AV1,
+                preferred_payload_type: None,
+                clock_rate: NonZeroU32::new(90000).unwrap(),
+                parameters: RtpCodecParametersParameters::from([
+                    ("level-idx", 5u32.into()),
+                    ("profile", 0u32.into()),
+                    ("tier", 0u32.into()),
+                ]),
                 rtcp_feedback: vec![
                     RtcpFeedback::Nack,
                     RtcpFeedback::NackPli,
@@ -202,6 +221,7 @@ pub fn get_supported_rtp_capabilities() -> RtpCapabilities {
                     RtcpFeedback::CcmFir,
                     RtcpFeedback::GoogRemb,
                     RtcpFeedback::TransportCC,
+                    RtcpFeedback::Lntf,
                 ],
             },
             RtpCodecCapability::Video {
@@ -218,6 +238,7 @@ pub fn get_supported_rtp_capabilities() -> RtpCapabilities {
                     RtcpFeedback::CcmFir,
                     RtcpFeedback::GoogRemb,
                     RtcpFeedback::TransportCC,
+                    RtcpFeedback::Lntf,
                 ],
             },
             RtpCodecCapability::Video {
@@ -234,6 +255,7 @@ pub fn get_supported_rtp_capabilities() -> RtpCapabilities {
                     RtcpFeedback::CcmFir,
                     RtcpFeedback::GoogRemb,
                     RtcpFeedback::TransportCC,
+                    RtcpFeedback::Lntf,
                 ],
             },
             RtpCodecCapability::Video {
@@ -250,8 +272,36 @@ pub fn get_supported_rtp_capabilities() -> RtpCapabilities {
                     RtcpFeedback::CcmFir,
                     RtcpFeedback::GoogRemb,
                     RtcpFeedback::TransportCC,
+                    RtcpFeedback::Lntf,
                 ],
             },
+            // NOTE: The `apt` parameter that would tie a RED stream to the payload type it is
+            // protecting, the same way RTX does, can only be resolved once a concrete codec has
+            // actually been negotiated, so it is left unset at the capabilities level here.
+            RtpCodecCapability::Video {
+                mime_type: MimeTypeVideo::RED,
+                preferred_payload_type: None,
+                clock_rate: NonZeroU32::new(90000).unwrap(),
+                parameters: RtpCodecParametersParameters::new(),
+                rtcp_feedback: vec![],
+            },
+            RtpCodecCapability::Video {
+                mime_type: MimeTypeVideo::ULPFEC,
+                preferred_payload_type: None,
+                clock_rate: NonZeroU32::new(90000).unwrap(),
+                parameters: RtpCodecParametersParameters::new(),
+                rtcp_feedback: vec![],
+            },
+            RtpCodecCapability::Video {
+                mime_type: MimeTypeVideo::FlexFEC03,
+                preferred_payload_type: None,
+                clock_rate: NonZeroU32::new(90000).unwrap(),
+                parameters: RtpCodecParametersParameters::from([(
+                    "repair-window",
+                    200_000u32.into(),
+                )]),
+                rtcp_feedback: vec![],
+            },
         ],
         header_extensions: vec![
             RtpHeaderExtension {
@@ -347,7 +397,82 @@ pub fn get_supported_rtp_capabilities() -> RtpCapabilities {
                 preferred_encrypt: false,
                 direction: RtpHeaderExtensionDirection::SendRecv,
             },
+            // Carries AV1's temporal/spatial layer structure, needed to make use of AV1 SVC.
+            RtpHeaderExtension {
+                kind: Some(MediaKind::Video),
+                uri: RtpHeaderExtensionUri::DependencyDescriptor,
+                preferred_id: 13,
+                preferred_encrypt: false,
+                direction: RtpHeaderExtensionDirection::SendRecv,
+            },
         ],
-        fec_mechanisms: vec![],
+        fec_mechanisms: vec![FecMechanism::Red, FecMechanism::UlpFec, FecMechanism::FlexFec],
+    }
+}
+
+/// Builder for [`RtpCapabilities`] that starts from mediasoup's default supported set (the same
+/// one returned by [`get_supported_rtp_capabilities`]) and lets the caller add, remove, or
+/// reorder codecs and header extensions before committing to the final set a
+/// [`Router`](crate::router::Router) is configured with.
+///
+/// Fields are `pub` so trimming unused codecs (e.g. iLBC, the less common SILK clock rates) or
+/// tuning a codec's advertised `parameters` (e.g. VP9 `profile-id`, H264
+/// `x-google-start-bitrate`) is just ordinary `Vec` manipulation rather than a dedicated method
+/// per tweak.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct SupportedRtpCapabilitiesBuilder {
+    /// Supported media and FEC codecs, in negotiation-preference order.
+    pub codecs: Vec<RtpCodecCapability>,
+    /// Supported RTP header extensions.
+    pub header_extensions: Vec<RtpHeaderExtension>,
+    /// Supported FEC mechanisms.
+    pub fec_mechanisms: Vec<FecMechanism>,
+}
+
+impl Default for SupportedRtpCapabilitiesBuilder {
+    fn default() -> Self {
+        let defaults = get_supported_rtp_capabilities();
+
+        Self {
+            codecs: defaults.codecs.unwrap_or_default(),
+            header_extensions: defaults.header_extensions.unwrap_or_default(),
+            fec_mechanisms: defaults.fec_mechanisms.unwrap_or_default(),
+        }
+    }
+}
+
+impl SupportedRtpCapabilitiesBuilder {
+    /// Starts from mediasoup's default supported RTP capabilities.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Changes the direction of a header extension identified by its `kind` and `uri`, leaving it
+    /// untouched if no such extension is currently present. To disable an extension entirely,
+    /// remove it from [`Self::header_extensions`] instead.
+    pub fn set_header_extension_direction(
+        &mut self,
+        kind: Option<MediaKind>,
+        uri: RtpHeaderExtensionUri,
+        direction: RtpHeaderExtensionDirection,
+    ) {
+        let extension = self
+            .header_extensions
+            .iter_mut()
+            .find(|extension| extension.kind == kind && extension.uri == uri);
+
+        if let Some(extension) = extension {
+            extension.direction = direction;
+        }
+    }
+
+    /// Finalizes the builder into the [`RtpCapabilities`] mediasoup negotiates against.
+    pub fn build(self) -> RtpCapabilities {
+        RtpCapabilities {
+            codecs: Some(self.codecs),
+            header_extensions: Some(self.header_extensions),
+            fec_mechanisms: Some(self.fec_mechanisms),
+        }
     }
 }
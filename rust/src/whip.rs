@@ -0,0 +1,136 @@
+//! [WHIP](https://datatracker.ietf.org/doc/draft-ietf-wish-whip/)/[WHEP](https://datatracker.ietf.org/doc/draft-ietf-wish-whep/)
+//! signaling on top of [`WebRtcTransport`](crate::webrtc_transport::WebRtcTransport), so a
+//! browser or an OBS-style encoder can publish/play with a single HTTP POST of an SDP offer
+//! instead of a bespoke app-specific signaling protocol. Not yet declared as `mod whip;`
+//! anywhere — see `CONTRIBUTING.md` for why.
+//!
+//! This module is deliberately transport-agnostic about the HTTP side, matching how WHIP clients
+//! in the wild (e.g. the gst WHIP client element) treat the signaling channel as "just an HTTP
+//! endpoint": [`WhipHandler`] exposes plain `&str`-in/`String`-out methods an application wires
+//! up to whatever HTTP server it already runs (hyper, axum, actix-web, ...) rather than this
+//! crate embedding one of its own. [`ice_server_link_header`] builds the `Link` header such a
+//! handler's HTTP layer should attach to the response, advertising STUN/TURN servers per the WHIP
+//! draft's `ice-server` link relation.
+//!
+//! What's genuinely implemented here: resource-id bookkeeping ([`WhipResourceId`]) and `Link`
+//! header construction ([`ice_server_link_header`]), both fully self-contained. What's *not*:
+//! actually creating a transport and producer/consumer on an offer. That needs a
+//! `Router`/`WebRtcTransport` pair with real `create_*`/`connect`/`produce`/`consume` methods,
+//! which this crate snapshot doesn't have (`router.rs`, `webrtc_transport.rs`, and `transport.rs`
+//! have no implementation file in this snapshot); the SDP side is covered by [`crate::sdp`]'s
+//! [`parse_offer`](crate::sdp::parse_offer)/[`build_answer`](crate::sdp::build_answer). The
+//! [`WhipHandler::publish`]/[`WhipHandler::play`] default methods below document the call
+//! sequence a working implementation needs and return
+//! [`WhipError::UnavailableDependency`] honestly rather than faking success.
+
+use crate::uuid_based_wrapper_type;
+use std::fmt;
+use thiserror::Error;
+
+uuid_based_wrapper_type!(
+    /// Identifies a WHIP/WHEP session, embedded in the resource URL (`Location` header) handed
+    /// back in the answer so a later `DELETE` on that URL can find the session to tear down.
+    WhipResourceId
+);
+
+/// A STUN/TURN server to advertise to the client via the WHIP `ice-server` `Link` header, per
+/// <https://datatracker.ietf.org/doc/draft-ietf-wish-whip/> section on ICE server configuration.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct IceServer {
+    /// One or more `stun:`/`turn:`/`turns:` URLs for this server.
+    pub urls: Vec<String>,
+    /// Username for TURN long-term credentials, if this is a TURN server.
+    pub username: Option<String>,
+    /// Credential (password) for TURN long-term credentials, if this is a TURN server.
+    pub credential: Option<String>,
+}
+
+impl fmt::Display for IceServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}>; rel=\"ice-server\"", self.urls.join(","))?;
+        if let Some(username) = &self.username {
+            write!(f, "; username=\"{username}\"")?;
+        }
+        if let Some(credential) = &self.credential {
+            write!(f, "; credential=\"{credential}\"; credential-type=\"password\"")?;
+        }
+        Ok(())
+    }
+}
+
+/// Builds the value of one or more `Link` headers advertising `ice_servers`, joined with `, ` so
+/// they can be sent as a single header line (equivalent to repeating the header once per server).
+pub fn ice_server_link_header(ice_servers: &[IceServer]) -> String {
+    ice_servers
+        .iter()
+        .map(IceServer::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Error returned by a [`WhipHandler`] method.
+#[derive(Debug, Error)]
+pub enum WhipError {
+    /// The offer's SDP couldn't be parsed, or an answer's SDP couldn't be generated.
+    #[error("failed to process session description: {0}")]
+    SdpError(String),
+    /// No session exists for the given [`WhipResourceId`] (e.g. a `DELETE` for an unknown or
+    /// already-torn-down resource).
+    #[error("no WHIP/WHEP session found for resource {0}")]
+    UnknownResource(WhipResourceId),
+    /// This method needs a dependency (a `Router`/`WebRtcTransport` implementation) that doesn't
+    /// exist in this crate snapshot yet.
+    #[error("WHIP/WHEP support is unavailable: {0}")]
+    UnavailableDependency(&'static str),
+}
+
+/// The result of successfully negotiating a WHIP (publish) or WHEP (play) session: an SDP answer
+/// and the resource identifier a caller embeds in the `Location` header of its HTTP response, so
+/// a later `DELETE` on that URL can be routed back to [`WhipHandler::destroy`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct WhipAnswer {
+    /// The SDP answer to return as the HTTP response body.
+    pub sdp: String,
+    /// Identifies this session for a later [`WhipHandler::destroy`] call.
+    pub resource_id: WhipResourceId,
+}
+
+/// Framework-agnostic WHIP/WHEP session negotiation. An application wires this up to whatever
+/// HTTP server it runs: `POST` the request body's SDP offer to [`publish`](Self::publish) (WHIP)
+/// or [`play`](Self::play) (WHEP), return the resulting SDP answer as the response body with a
+/// `Location` header built from [`WhipAnswer::resource_id`], and route a `DELETE` on that
+/// location to [`destroy`](Self::destroy).
+pub trait WhipHandler {
+    /// Publishes media: creates a transport and producer(s) from `offer_sdp`'s media sections and
+    /// answers with the resulting local SDP. A working implementation would, roughly:
+    /// 1. `router.create_webrtc_transport(...)` to get a `WebRtcTransport`.
+    /// 2. Parse `offer_sdp` for ICE ufrag/pwd, DTLS fingerprint, and per-media `RtpParameters`.
+    /// 3. `transport.connect(...)` with the offer's ICE/DTLS parameters.
+    /// 4. `transport.produce(...)` once per offered media section.
+    /// 5. Generate an SDP answer carrying the transport's own ICE/DTLS parameters.
+    fn publish(&self, offer_sdp: &str) -> Result<WhipAnswer, WhipError> {
+        let _ = offer_sdp;
+        Err(WhipError::UnavailableDependency(
+            "publishing needs a Router/WebRtcTransport implementation, which doesn't exist in \
+             this crate snapshot",
+        ))
+    }
+
+    /// Plays media: creates a transport and consumer(s) matching `offer_sdp`'s requested media
+    /// against the router's `RtpCapabilities` (see [`crate::ortc::get_consumer_rtp_parameters`]),
+    /// and answers with the resulting local SDP. Otherwise symmetric to
+    /// [`publish`](Self::publish).
+    fn play(&self, offer_sdp: &str) -> Result<WhipAnswer, WhipError> {
+        let _ = offer_sdp;
+        Err(WhipError::UnavailableDependency(
+            "playing needs a Router/WebRtcTransport implementation, which doesn't exist in this \
+             crate snapshot",
+        ))
+    }
+
+    /// Tears down the transport (and any producers/consumers on it) backing `resource_id`,
+    /// in response to a `DELETE` on its resource URL.
+    fn destroy(&self, resource_id: WhipResourceId) -> Result<(), WhipError> {
+        Err(WhipError::UnknownResource(resource_id))
+    }
+}
@@ -1,22 +1,24 @@
 //! A worker represents a mediasoup C++ subprocess that runs on a single CPU core and handles
 //! [`Router`] instances.
 
-// TODO: This is Unix-specific and doesn't support Windows in any way
 mod channel;
 mod common;
+mod correlation;
 mod payload_channel;
 mod utils;
 
 use crate::data_structures::AppData;
 use crate::messages::{
-    RouterInternal, WorkerCreateRouterRequest, WorkerDumpRequest, WorkerGetResourceRequest,
-    WorkerUpdateSettingsRequest,
+    RouterInternal, WorkerCloseRequest, WorkerCreateRouterRequest, WorkerDumpRequest,
+    WorkerGetResourceRequest, WorkerUpdateSettingsRequest,
 };
 use crate::ortc;
 use crate::ortc::RtpCapabilitiesError;
 use crate::router::{Router, RouterId, RouterOptions};
 use crate::worker_manager::WorkerManager;
 use async_executor::Executor;
+use async_io::Timer;
+use async_net::TcpStream;
 use async_process::{Child, Command, ExitStatus, Stdio};
 pub(crate) use channel::Channel;
 pub(crate) use common::{SubscriptionHandler, SubscriptionTarget};
@@ -27,17 +29,20 @@ use log::*;
 use parking_lot::Mutex;
 pub(crate) use payload_channel::{NotificationError, NotificationMessage, PayloadChannel};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::ffi::OsString;
+use std::net::SocketAddr;
 use std::ops::RangeInclusive;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::{env, io};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::{env, fmt, io};
 use thiserror::Error;
 use utils::SpawnResult;
 
 /// Error that caused request to mediasoup-worker subprocess to fail.
-#[derive(Debug, Error, Eq, PartialEq)]
+#[derive(Debug, Clone, Error, Eq, PartialEq)]
 pub enum RequestError {
     /// Channel already closed
     #[error("Channel already closed")]
@@ -160,11 +165,167 @@ pub struct WorkerDtlsFiles {
     pub private_key: PathBuf,
 }
 
-/// Settings for worker to be created with.
+/// Where a worker's `mediasoup-worker` process runs.
+#[derive(Debug, Clone)]
+pub enum WorkerSource {
+    /// Spawn `mediasoup-worker` as a subprocess on the local host (the default).
+    Local,
+    /// Connect to the control and payload channels of a `mediasoup-worker` that is already
+    /// running on a remote host, instead of spawning a local subprocess, so a single
+    /// `WorkerManager` can fan routers out across a cluster of machines.
+    ///
+    /// This crate only speaks plain TCP to `channel_address`/`payload_channel_address`; route
+    /// the connection through an SSH tunnel, mTLS-terminating proxy, or similar if it needs to
+    /// be authenticated or encrypted.
+    Remote {
+        /// Address the remote worker's control channel is listening on.
+        channel_address: SocketAddr,
+        /// Address the remote worker's payload channel is listening on.
+        payload_channel_address: SocketAddr,
+        /// How many times to reconnect (with the default [`RestartPolicy`]'s backoff) after the
+        /// link is lost; each reconnected link that is lost again within the default policy's
+        /// stability window counts as one attempt. The initial connection is made once, without
+        /// retrying, and a reconnect that fails to connect gives up at once.
+        reconnect_attempts: u32,
+    },
+}
+
+impl Default for WorkerSource {
+    fn default() -> Self {
+        Self::Local
+    }
+}
+
+/// Backoff policy controlling whether and how aggressively a [`Worker`] respawns the
+/// mediasoup-worker subprocess after it unexpectedly dies.
+///
+/// Disabled by default; opt in via [`WorkerSettings::restart_policy`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RestartPolicy {
+    /// Delay before the first respawn attempt.
+    pub base_delay: Duration,
+    /// Upper bound the exponential backoff (`base_delay * 2^attempt`) is capped at.
+    pub max_delay: Duration,
+    /// Random jitter applied to each computed delay, as a fraction of it (e.g. `0.2` means the
+    /// actual delay used is anywhere from 80% to 120% of the computed value).
+    pub jitter: f64,
+    /// Give up restarting (and fire `on_dead`/`on_close` as if restarting were disabled) after
+    /// this many consecutive failed attempts. `None` never gives up.
+    pub max_attempts: Option<u32>,
+    /// How long a respawned process needs to stay alive before the backoff attempt counter
+    /// resets back to 0.
+    pub stability_window: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(30),
+            jitter: 0.2,
+            max_attempts: Some(5),
+            stability_window: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RestartPolicy {
+    fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let exponential = self.base_delay * 2u32.saturating_pow(attempt);
+        let capped = exponential.min(self.max_delay);
+
+        // No `rand` dependency elsewhere in the crate, so jitter is derived from the current
+        // time instead of pulling one in just for this.
+        let now_nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.subsec_nanos())
+            .unwrap_or(0);
+        let random_unit = f64::from(now_nanos % 1_000_000) / 1_000_000.0;
+        let jitter_factor = 1.0 + self.jitter * (random_unit * 2.0 - 1.0);
+
+        capped.mul_f64(jitter_factor.max(0.0))
+    }
+}
+
+/// Per-request timeout, and optional retry backoff for transient [`RequestError::TimedOut`]
+/// failures, applied to requests sent over a worker's channel.
+///
+/// Configurable as the default for all of a [`Worker`]'s requests via
+/// [`WorkerSettings::request_policy`], and overridable per call via the `*_with_policy` methods
+/// (e.g. [`Worker::dump_with_policy`]). Retries only ever apply to idempotent requests
+/// (`dump`/`get_resource_usage`/`update_settings`); non-idempotent ones like `create_router`
+/// enforce the timeout but never retry, regardless of `retry`.
 #[derive(Debug, Clone)]
+pub struct RequestPolicy {
+    /// How long to wait for a response before failing this attempt with
+    /// `RequestError::TimedOut`.
+    pub timeout: Duration,
+    /// When set, a request that times out is retried with this backoff (the same
+    /// exponential-backoff-with-jitter scheme [`RestartPolicy`] uses for respawns) instead of
+    /// immediately returning `RequestError::TimedOut`. `None` never retries.
+    pub retry: Option<RestartPolicy>,
+}
+
+impl Default for RequestPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(5),
+            retry: None,
+        }
+    }
+}
+
+/// Races `request`'s result against `policy.timeout`, retrying (on a fresh clone of `request`)
+/// per `policy.retry` when `retryable` is set and the attempt times out.
+///
+/// The timeout is enforced by racing the response future against a timer via [`future::or`]:
+/// when the timer wins, the response future is dropped, which cancels its in-flight channel
+/// subscription so it can't later resolve into a stale slot.
+async fn request_with_policy<Req>(
+    channel: &Channel,
+    policy: &RequestPolicy,
+    retryable: bool,
+    request: Req,
+) -> Result<Req::Response, RequestError>
+where
+    Req: crate::messages::Request + Clone,
+{
+    let mut attempt: u32 = 0;
+    loop {
+        let result = future::or(
+            async {
+                Timer::after(policy.timeout).await;
+                Err(RequestError::TimedOut)
+            },
+            channel.request(request.clone()),
+        )
+        .await;
+
+        let timed_out = matches!(result, Err(RequestError::TimedOut));
+        if !(retryable && timed_out) {
+            return result;
+        }
+
+        match &policy.retry {
+            Some(retry) if !matches!(retry.max_attempts, Some(max) if attempt >= max) => {
+                Timer::after(retry.delay_for_attempt(attempt)).await;
+                attempt += 1;
+            }
+            _ => return result,
+        }
+    }
+}
+
+/// Settings for worker to be created with.
+#[derive(Clone)]
 #[non_exhaustive]
 pub struct WorkerSettings {
     pub app_data: AppData,
+    /// Where to run (or find an already-running) `mediasoup-worker` process.
+    ///
+    /// Default [`WorkerSource::Local`].
+    pub source: WorkerSource,
     /// Logging level for logs generated by the media worker subprocesses.
     ///
     /// Default [`WorkerLogLevel::Error`].
@@ -178,16 +339,65 @@ pub struct WorkerSettings {
     ///
     /// If `None`, a certificate is dynamically created.
     pub dtls_files: Option<WorkerDtlsFiles>,
+    /// How long [`Worker::close`] waits for the worker subprocess to exit after asking it to
+    /// close itself before escalating to a hard kill.
+    pub close_timeout: Duration,
+    /// When set, the worker subprocess is automatically respawned (with backoff) if it dies
+    /// unexpectedly instead of the [`Worker`] becoming permanently closed.
+    ///
+    /// Default `None` (disabled).
+    pub restart_policy: Option<RestartPolicy>,
+    /// When set, periodically samples [`Worker::get_resource_usage`] at this interval, computing
+    /// a [`WorkerMetricsSample`] (retrievable via [`Worker::metrics_snapshot`]) and feeding it
+    /// into `metrics_recorder` if one is installed.
+    ///
+    /// Default `None` (disabled).
+    pub resource_sampling_interval: Option<Duration>,
+    /// Sink the periodic [`WorkerMetricsSample`]s are fed into when
+    /// `resource_sampling_interval` is set.
+    ///
+    /// Default `None`.
+    pub metrics_recorder: Option<Arc<dyn WorkerMetricsRecorder + Send + Sync>>,
+    /// Default timeout/retry policy for requests sent over this worker's channel, overridable
+    /// per call via the `*_with_policy` methods.
+    pub request_policy: RequestPolicy,
+}
+
+impl fmt::Debug for WorkerSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WorkerSettings")
+            .field("app_data", &self.app_data)
+            .field("source", &self.source)
+            .field("log_level", &self.log_level)
+            .field("log_tags", &self.log_tags)
+            .field("rtc_ports_range", &self.rtc_ports_range)
+            .field("dtls_files", &self.dtls_files)
+            .field("close_timeout", &self.close_timeout)
+            .field("restart_policy", &self.restart_policy)
+            .field(
+                "resource_sampling_interval",
+                &self.resource_sampling_interval,
+            )
+            .field("metrics_recorder", &self.metrics_recorder.is_some())
+            .field("request_policy", &self.request_policy)
+            .finish()
+    }
 }
 
 impl Default for WorkerSettings {
     fn default() -> Self {
         Self {
             app_data: AppData::default(),
+            source: WorkerSource::default(),
             log_level: WorkerLogLevel::default(),
             log_tags: Vec::new(),
             rtc_ports_range: 10000..=59999,
             dtls_files: None,
+            close_timeout: Duration::from_secs(2),
+            restart_policy: None,
+            resource_sampling_interval: None,
+            metrics_recorder: None,
+            request_policy: RequestPolicy::default(),
         }
     }
 }
@@ -246,6 +456,69 @@ pub struct WorkerResourceUsage {
     pub ru_nivcsw: u64,
 }
 
+/// A single point-in-time resource usage measurement produced by the periodic sampler started
+/// via [`WorkerSettings::resource_sampling_interval`], with `ru_utime`/`ru_stime` already diffed
+/// against the previous sample into a CPU percentage covering that interval.
+#[derive(Debug, Copy, Clone)]
+#[non_exhaustive]
+pub struct WorkerMetricsSample {
+    /// PID of the worker subprocess the sample was taken from.
+    pub pid: u32,
+    /// CPU usage over the sampling interval, as a percentage of one core (so it can exceed 100%
+    /// on a busy worker that uses more than one core's worth of time, e.g. due to GC threads).
+    pub cpu_percent: f64,
+    /// Maximum resident set size, same units as [`WorkerResourceUsage::ru_maxrss`].
+    pub maxrss: u64,
+    /// Voluntary context switches since the worker started.
+    pub nvcsw: u64,
+    /// Involuntary context switches since the worker started.
+    pub nivcsw: u64,
+}
+
+/// Sink for periodic [`WorkerMetricsSample`]s, installed via
+/// [`WorkerSettings::metrics_recorder`]. Implement this to feed worker resource usage into
+/// whatever monitoring system an application already uses.
+pub trait WorkerMetricsRecorder {
+    /// Called once per [`WorkerSettings::resource_sampling_interval`] tick with the latest
+    /// sample for a worker.
+    fn record(&self, sample: WorkerMetricsSample);
+}
+
+/// Default [`WorkerMetricsRecorder`] that keeps the latest sample per worker PID and can render
+/// them as Prometheus text-format gauges, e.g. for load-balancing decisions across workers or for
+/// scraping by a Prometheus instance.
+#[derive(Debug, Default)]
+pub struct PrometheusMetricsRecorder {
+    samples: Mutex<HashMap<u32, WorkerMetricsSample>>,
+}
+
+impl WorkerMetricsRecorder for PrometheusMetricsRecorder {
+    fn record(&self, sample: WorkerMetricsSample) {
+        self.samples.lock().insert(sample.pid, sample);
+    }
+}
+
+impl PrometheusMetricsRecorder {
+    /// Renders all currently known samples as PID-labeled Prometheus text-format gauges.
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+        for sample in self.samples.lock().values() {
+            output.push_str(&format!(
+                "mediasoup_worker_cpu_percent{{pid=\"{pid}\"}} {cpu_percent}\n\
+                 mediasoup_worker_maxrss_bytes{{pid=\"{pid}\"}} {maxrss}\n\
+                 mediasoup_worker_voluntary_context_switches{{pid=\"{pid}\"}} {nvcsw}\n\
+                 mediasoup_worker_involuntary_context_switches{{pid=\"{pid}\"}} {nivcsw}\n",
+                pid = sample.pid,
+                cpu_percent = sample.cpu_percent,
+                maxrss = sample.maxrss,
+                nvcsw = sample.nvcsw,
+                nivcsw = sample.nivcsw,
+            ));
+        }
+        output
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[doc(hidden)]
@@ -266,296 +539,896 @@ pub enum CreateRouterError {
     Request(RequestError),
 }
 
+/// Error that caused [`Worker::new`] to fail.
+#[derive(Debug, Error)]
+pub enum WorkerError {
+    /// Failed to spawn the worker subprocess or communicate with it before it became ready.
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    /// The worker subprocess reported a version this crate doesn't know how to drive; the
+    /// `major.minor` of both must match since that's what determines the channel protocol.
+    #[error("Worker version mismatch: expected {expected}, got {got}")]
+    VersionMismatch {
+        /// `major.minor` version expected by this crate.
+        expected: String,
+        /// `major.minor` version the worker subprocess reported during startup.
+        got: String,
+    },
+}
+
+/// Worker version and capability handshake carried by its startup `running` notification.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RunningHandshake {
+    version: String,
+    /// Optional features the worker subprocess reports supporting (e.g. SVC, transport types,
+    /// codecs), exposed to callers via [`Worker::capabilities`].
+    #[serde(default)]
+    capabilities: Vec<String>,
+    /// PID of the worker process. Ignored for [`WorkerSource::Local`] (the local `Child` already
+    /// knows its own pid), used as the worker's reported pid for [`WorkerSource::Remote`], since
+    /// there is no local `Child` to ask.
+    #[serde(default)]
+    pid: Option<u32>,
+}
+
+/// Checks that `worker_version`'s `major.minor` matches this crate's, since that's what
+/// determines compatibility of the channel protocol between the two.
+fn check_version_compatibility(worker_version: &str) -> Result<(), WorkerError> {
+    let expected = format!(
+        "{}.{}",
+        env!("CARGO_PKG_VERSION_MAJOR"),
+        env!("CARGO_PKG_VERSION_MINOR"),
+    );
+    let got = worker_version
+        .splitn(3, '.')
+        .take(2)
+        .collect::<Vec<_>>()
+        .join(".");
+
+    if got == expected {
+        Ok(())
+    } else {
+        Err(WorkerError::VersionMismatch { expected, got })
+    }
+}
+
+/// The subset of [`WorkerSettings`] needed to spawn (or, with a `restart_policy`, respawn) the
+/// subprocess, kept around on [`Inner`] for as long as the worker lives.
+#[derive(Debug, Clone)]
+struct SpawnSettings {
+    source: WorkerSource,
+    worker_binary: PathBuf,
+    log_level: WorkerLogLevel,
+    log_tags: Vec<WorkerLogTag>,
+    rtc_ports_range: RangeInclusive<u16>,
+    dtls_files: Option<WorkerDtlsFiles>,
+}
+
+/// Handle to the process backing a [`Generation`]. `Local` owns the subprocess directly; `Remote`
+/// has none to own or signal, since it's just a TCP link to a process running elsewhere.
+enum WorkerProcess {
+    Local(Child),
+    Remote,
+}
+
+/// Everything that gets replaced wholesale when the worker subprocess is respawned (or, for
+/// [`WorkerSource::Remote`], reconnected).
+struct Generation {
+    process: WorkerProcess,
+    channel: Channel,
+    payload_channel: PayloadChannel,
+    pid: u32,
+    capabilities: Vec<String>,
+}
+
+/// Spawns (or, for [`WorkerSource::Remote`], connects to) the worker process described by
+/// `spawn_settings`, wires up message forwarding, and waits for its startup handshake. Used both
+/// for the initial spawn in [`Inner::new`] and for every respawn/reconnect attempt made by the
+/// `restart_policy`/remote-reconnection supervisors.
+async fn spawn_generation(
+    executor: &Arc<Executor<'static>>,
+    closed: &Arc<AtomicBool>,
+    spawn_settings: &SpawnSettings,
+) -> Result<Generation, WorkerError> {
+    match &spawn_settings.source {
+        WorkerSource::Local => spawn_local_generation(executor, closed, spawn_settings).await,
+        WorkerSource::Remote {
+            channel_address,
+            payload_channel_address,
+            ..
+        } => {
+            connect_remote_generation(executor, closed, *channel_address, *payload_channel_address)
+                .await
+        }
+    }
+}
+
+async fn spawn_local_generation(
+    executor: &Arc<Executor<'static>>,
+    closed: &Arc<AtomicBool>,
+    spawn_settings: &SpawnSettings,
+) -> Result<Generation, WorkerError> {
+    let mut spawn_args: Vec<OsString> = Vec::new();
+    let spawn_bin: PathBuf = match env::var("MEDIASOUP_USE_VALGRIND") {
+        Ok(value) if value.as_str() == "true" => {
+            let binary = match env::var("MEDIASOUP_VALGRIND_BIN") {
+                Ok(binary) => binary.into(),
+                _ => "valgrind".into(),
+            };
+
+            spawn_args.push(spawn_settings.worker_binary.clone().into_os_string());
+
+            binary
+        }
+        _ => spawn_settings.worker_binary.clone(),
+    };
+
+    spawn_args.push(format!("--logLevel={}", spawn_settings.log_level.as_str()).into());
+    for log_tag in &spawn_settings.log_tags {
+        spawn_args.push(format!("--logTag={}", log_tag.as_str()).into());
+    }
+
+    if spawn_settings.rtc_ports_range.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "Invalid RTC ports range").into());
+    }
+    spawn_args.push(format!("--rtcMinPort={}", spawn_settings.rtc_ports_range.start()).into());
+    spawn_args.push(format!("--rtcMaxPort={}", spawn_settings.rtc_ports_range.end()).into());
+
+    if let Some(dtls_files) = &spawn_settings.dtls_files {
+        {
+            let mut arg = OsString::new();
+            arg.push("--dtlsCertificateFile=");
+            arg.push(&dtls_files.certificate);
+            spawn_args.push(arg);
+        }
+        {
+            let mut arg = OsString::new();
+            arg.push("--dtlsPrivateKeyFile=");
+            arg.push(&dtls_files.private_key);
+            spawn_args.push(arg);
+        }
+    }
+
+    debug!(
+        "spawning worker process: {} {}",
+        spawn_bin.to_string_lossy(),
+        spawn_args
+            .iter()
+            .map(|arg| arg.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+
+    let mut command = Command::new(spawn_bin);
+    command
+        .args(spawn_args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .env("MEDIASOUP_VERSION", env!("CARGO_PKG_VERSION"));
+
+    let SpawnResult {
+        mut child,
+        channel,
+        payload_channel,
+    } = utils::spawn_with_worker_channels(Arc::clone(executor), &mut command)?;
+
+    let pid = child.id();
+
+    setup_output_forwarding(executor, closed, &mut child);
+    setup_message_handling(executor, closed, pid, &channel, &payload_channel);
+
+    let handshake = wait_for_worker_process(&mut child, &channel, pid).await?;
+    check_version_compatibility(&handshake.version)?;
+
+    Ok(Generation {
+        process: WorkerProcess::Local(child),
+        channel,
+        payload_channel,
+        pid,
+        capabilities: handshake.capabilities,
+    })
+}
+
+/// Connects to a `mediasoup-worker` already running on a remote host, per [`WorkerSource::Remote`].
+/// There is no local subprocess to wait on for an early-exit race (unlike
+/// [`spawn_local_generation`]), so this just waits for the handshake directly.
+async fn connect_remote_generation(
+    executor: &Arc<Executor<'static>>,
+    closed: &Arc<AtomicBool>,
+    channel_address: SocketAddr,
+    payload_channel_address: SocketAddr,
+) -> Result<Generation, WorkerError> {
+    debug!(
+        "connecting to remote worker: channel {}, payload channel {}",
+        channel_address, payload_channel_address,
+    );
+
+    let channel_stream = TcpStream::connect(channel_address).await?;
+    let payload_channel_stream = TcpStream::connect(payload_channel_address).await?;
+
+    let channel = Channel::new(
+        Arc::clone(executor),
+        channel_stream.clone(),
+        channel_stream,
+    );
+    let payload_channel = PayloadChannel::new(
+        Arc::clone(executor),
+        payload_channel_stream.clone(),
+        payload_channel_stream,
+    );
+
+    // A remote worker's pid (reported in its handshake, since there's no local `Child` to ask)
+    // is only used as an opaque notification-subscription key here, so 0 is a safe fallback if a
+    // remote worker's handshake doesn't carry one.
+    let placeholder_pid = 0;
+    let handshake = wait_for_worker_ready(&channel, placeholder_pid).await?;
+    check_version_compatibility(&handshake.version)?;
+    let pid = handshake.pid.unwrap_or(placeholder_pid);
+
+    setup_message_handling(executor, closed, pid, &channel, &payload_channel);
+
+    Ok(Generation {
+        process: WorkerProcess::Remote,
+        channel,
+        payload_channel,
+        pid,
+        capabilities: handshake.capabilities,
+    })
+}
+
+fn setup_output_forwarding(
+    executor: &Arc<Executor<'static>>,
+    closed: &Arc<AtomicBool>,
+    child: &mut Child,
+) {
+    let stdout = child.stdout.take().unwrap();
+    executor
+        .spawn(async move {
+            let mut lines = BufReader::new(stdout).lines();
+            while let Some(Ok(line)) = lines.next().await {
+                debug!("(stdout) {}", line);
+            }
+        })
+        .detach();
+
+    let stderr = child.stderr.take().unwrap();
+    let closed = Arc::clone(closed);
+    executor
+        .spawn(async move {
+            let mut lines = BufReader::new(stderr).lines();
+            while let Some(Ok(line)) = lines.next().await {
+                if !closed.load(Ordering::SeqCst) {
+                    error!("(stderr) {}", line);
+                }
+            }
+        })
+        .detach();
+}
+
+async fn wait_for_worker_process(
+    child: &mut Child,
+    channel: &Channel,
+    pid: u32,
+) -> Result<RunningHandshake, WorkerError> {
+    let status = child.status();
+    future::or(
+        async move {
+            let status = status.await?;
+            let error_message = format!(
+                "worker process exited before being ready, exit status {}, code {:?}",
+                status,
+                status.code(),
+            );
+            Err(io::Error::new(io::ErrorKind::NotFound, error_message).into())
+        },
+        wait_for_worker_ready(channel, pid),
+    )
+    .await
+}
+
+async fn wait_for_worker_ready(
+    channel: &Channel,
+    pid: u32,
+) -> Result<RunningHandshake, WorkerError> {
+    #[derive(Deserialize)]
+    #[serde(tag = "event", rename_all = "lowercase", content = "data")]
+    enum Notification {
+        Running(RunningHandshake),
+    }
+
+    let (sender, receiver) = async_oneshot::oneshot();
+    let sender = Mutex::new(Some(sender));
+    let _handler = channel.subscribe_to_notifications(pid.into(), move |notification| {
+        let result = match serde_json::from_value(notification.clone()) {
+            Ok(Notification::Running(handshake)) => {
+                debug!(
+                    "worker process running [pid:{}, version:{}]",
+                    pid, handshake.version
+                );
+                Ok(handshake)
+            }
+            Err(error) => Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!(
+                    "unexpected first notification from worker [pid:{}]: {:?}; error = {}",
+                    pid, notification, error
+                ),
+            )
+            .into()),
+        };
+        let _ = sender
+            .lock()
+            .take()
+            .expect("Receiving more than one worker notification")
+            .send(result);
+    });
+
+    receiver.await.map_err(|_closed| {
+        WorkerError::from(io::Error::new(
+            io::ErrorKind::Other,
+            "Worker dropped before it is ready",
+        ))
+    })?
+}
+
+fn setup_message_handling(
+    executor: &Arc<Executor<'static>>,
+    closed: &Arc<AtomicBool>,
+    pid: u32,
+    channel: &Channel,
+    payload_channel: &PayloadChannel,
+) {
+    let channel_receiver = channel.get_internal_message_receiver();
+    let payload_channel_receiver = payload_channel.get_internal_message_receiver();
+    let closed_for_channel = Arc::clone(closed);
+    executor
+        .spawn(async move {
+            while let Ok(message) = channel_receiver.recv().await {
+                match message {
+                    channel::InternalMessage::Debug(text) => debug!("[pid:{}] {}", pid, text),
+                    channel::InternalMessage::Warn(text) => warn!("[pid:{}] {}", pid, text),
+                    channel::InternalMessage::Error(text) => {
+                        if !closed_for_channel.load(Ordering::SeqCst) {
+                            error!("[pid:{}] {}", pid, text)
+                        }
+                    }
+                    channel::InternalMessage::Dump(text) => eprintln!("{}", text),
+                    channel::InternalMessage::Unexpected(data) => error!(
+                        "worker[pid:{}] unexpected channel data: {}",
+                        pid,
+                        String::from_utf8_lossy(&data)
+                    ),
+                }
+            }
+        })
+        .detach();
+
+    executor
+        .spawn(async move {
+            while let Ok(message) = payload_channel_receiver.recv().await {
+                match message {
+                    payload_channel::InternalMessage::UnexpectedData(data) => error!(
+                        "worker[pid:{}] unexpected payload channel data: {}",
+                        pid,
+                        String::from_utf8_lossy(&data)
+                    ),
+                }
+            }
+        })
+        .detach();
+}
+
 #[derive(Default)]
 struct Handlers {
     new_router: Bag<Box<dyn Fn(&Router) + Send + Sync>>,
     dead: BagOnce<Box<dyn FnOnce(ExitStatus) + Send>>,
     close: BagOnce<Box<dyn FnOnce() + Send>>,
+    restart: Bag<Box<dyn Fn(&Worker) + Send + Sync>>,
 }
 
 struct Inner {
-    channel: Channel,
-    payload_channel: PayloadChannel,
-    child: Child,
+    generation: Mutex<Generation>,
     executor: Arc<Executor<'static>>,
-    pid: u32,
+    spawn_settings: SpawnSettings,
+    restart_policy: Option<RestartPolicy>,
     handlers: Handlers,
     app_data: AppData,
     closed: Arc<AtomicBool>,
+    /// Set as soon as an intentional shutdown (`Worker::close`/`Drop`) begins, independently of
+    /// `closed`, so that a subprocess exit observed during an intentional shutdown is never
+    /// mistaken by [`Self::handle_exit`] for an unexpected death worth respawning.
+    closing: Arc<AtomicBool>,
+    close_timeout: Duration,
+    metrics_recorder: Option<Arc<dyn WorkerMetricsRecorder + Send + Sync>>,
+    latest_metrics_sample: Mutex<Option<WorkerMetricsSample>>,
+    request_policy: RequestPolicy,
     // Make sure worker is not dropped until this worker manager is not dropped
     _worker_manager: WorkerManager,
 }
 
-impl Drop for Inner {
-    fn drop(&mut self) {
-        debug!("drop()");
-
-        let already_closed = self.closed.swap(true, Ordering::SeqCst);
-
-        if matches!(self.child.try_status(), Ok(None)) {
-            unsafe {
-                libc::kill(self.pid as libc::pid_t, libc::SIGTERM);
+impl Inner {
+    /// Sends `SIGKILL` (`Child::kill()` on Windows), the hard-kill escalation used once
+    /// [`Self::close`]'s graceful `close_timeout` has elapsed without the worker process exiting
+    /// on its own.
+    fn hard_kill(&self) {
+        let mut generation = self.generation.lock();
+        let pid = generation.pid;
+        match &mut generation.process {
+            WorkerProcess::Local(_child) => {
+                #[cfg(unix)]
+                unsafe {
+                    libc::kill(pid as libc::pid_t, libc::SIGKILL);
+                }
+                #[cfg(windows)]
+                {
+                    let _ = _child.kill();
+                }
             }
+            // No local process to signal; a remote worker is instead left to
+            // `spawn_remote_watcher`, which gives up at its next heartbeat once `closing` is set.
+            WorkerProcess::Remote => {}
         }
+    }
 
-        if !already_closed {
-            self.handlers.close.call_simple();
+    /// Asks the worker process to close itself, waiting up to `close_timeout` for it to actually
+    /// exit before escalating to [`Self::hard_kill`], after which it waits for the exit that the
+    /// kill causes. `on_dead`/`on_close` handlers are fired
+    /// exactly once by the exit-status watcher task regardless of whether the process went away
+    /// on its own, via this request, or via the hard kill below.
+    async fn close(&self) {
+        if self.closed.load(Ordering::SeqCst) {
+            return;
         }
-    }
-}
+        self.closing.store(true, Ordering::SeqCst);
 
-impl Inner {
-    async fn new(
-        executor: Arc<Executor<'static>>,
-        worker_binary: PathBuf,
-        WorkerSettings {
-            app_data,
-            log_level,
-            log_tags,
-            rtc_ports_range,
-            dtls_files,
-        }: WorkerSettings,
-        worker_manager: WorkerManager,
-    ) -> io::Result<Arc<Self>> {
-        debug!("new()");
+        let (sender, receiver) = async_oneshot::oneshot();
+        let sender = Mutex::new(Some(sender));
+        let _handler_id = self.handlers.close.add(Box::new(move || {
+            if let Some(mut sender) = sender.lock().take() {
+                let _ = sender.send(());
+            }
+        }));
 
-        let mut spawn_args: Vec<OsString> = Vec::new();
-        let spawn_bin: PathBuf = match env::var("MEDIASOUP_USE_VALGRIND") {
-            Ok(value) if value.as_str() == "true" => {
-                let binary = match env::var("MEDIASOUP_VALGRIND_BIN") {
-                    Ok(binary) => binary.into(),
-                    _ => "valgrind".into(),
-                };
+        let channel = self.generation.lock().channel.clone();
+        let _ = channel.request(WorkerCloseRequest {}).await;
 
-                spawn_args.push(worker_binary.into_os_string());
+        let exited = async {
+            let _ = receiver.await;
+        };
+        futures_lite::pin!(exited);
+        let exited_in_time = future::or(
+            async {
+                (&mut exited).await;
+                true
+            },
+            async {
+                Timer::after(self.close_timeout).await;
+                false
+            },
+        )
+        .await;
+        if !exited_in_time {
+            self.hard_kill();
+            exited.await;
+        }
+    }
 
-                binary
+    /// Spawns (or respawns) the task that watches the current generation's subprocess for its
+    /// exit status and reacts to it: giving up (firing `on_dead`/`on_close`) when there is no
+    /// `restart_policy`, the policy has run out of attempts, or the worker was intentionally
+    /// closed, and otherwise respawning with backoff and firing `on_restart`. A `Remote`
+    /// generation has no subprocess to watch an exit status from, so this just delegates to
+    /// [`Self::spawn_remote_watcher`] instead.
+    fn spawn_exit_watcher(inner: &Arc<Self>, attempt: u32, spawned_at: Instant) {
+        let status_fut = match &mut inner.generation.lock().process {
+            WorkerProcess::Local(child) => child.status(),
+            WorkerProcess::Remote => {
+                Self::spawn_remote_watcher(inner, attempt, spawned_at);
+                return;
             }
-            _ => worker_binary,
         };
+        let inner_weak = Arc::downgrade(inner);
+        inner
+            .executor
+            .spawn(async move {
+                let status = status_fut.await;
 
-        spawn_args.push(format!("--logLevel={}", log_level.as_str()).into());
-        for log_tag in log_tags {
-            spawn_args.push(format!("--logTag={}", log_tag.as_str()).into());
-        }
+                if let Some(inner) = inner_weak.upgrade() {
+                    if let Ok(exit_status) = status {
+                        warn!("exit status {}", exit_status);
+                        inner.handle_exit(exit_status, attempt, spawned_at).await;
+                    }
+                }
+            })
+            .detach();
+    }
 
-        if rtc_ports_range.is_empty() {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                "Invalid RTC ports range",
-            ));
+    /// Spawns (or respawns after a reconnect) the task that watches a `WorkerSource::Remote`
+    /// generation for disconnection, by periodically issuing a cheap request over its channel
+    /// since there's no local subprocess to wait an exit status from. Any request error is
+    /// treated as a disconnect, reacting the same way [`Self::spawn_exit_watcher`] reacts to a
+    /// process exit: reconnecting with backoff up to the source's `reconnect_attempts`, or giving
+    /// up via [`Self::give_up_remote`].
+    fn spawn_remote_watcher(inner: &Arc<Self>, attempt: u32, spawned_at: Instant) {
+        const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+        let channel = inner.generation.lock().channel.clone();
+        let closing = Arc::clone(&inner.closing);
+        let inner_weak = Arc::downgrade(inner);
+        inner
+            .executor
+            .spawn(async move {
+                loop {
+                    Timer::after(HEARTBEAT_INTERVAL).await;
+                    if closing.load(Ordering::SeqCst) {
+                        if let Some(inner) = inner_weak.upgrade() {
+                            inner.give_up_remote();
+                        }
+                        return;
+                    }
+                    if channel.request(WorkerDumpRequest {}).await.is_err() {
+                        break;
+                    }
+                }
+
+                if let Some(inner) = inner_weak.upgrade() {
+                    if closing.load(Ordering::SeqCst) {
+                        inner.give_up_remote();
+                    } else {
+                        warn!("remote worker disconnected");
+                        inner.handle_remote_disconnect(attempt, spawned_at).await;
+                    }
+                }
+            })
+            .detach();
+    }
+
+    /// Spawns the periodic resource-usage sampler enabled via
+    /// [`WorkerSettings::resource_sampling_interval`]. Re-reads the current generation's channel
+    /// and pid on every tick, so it keeps sampling the right subprocess across a respawn, and
+    /// stops once the worker starts closing.
+    fn spawn_metrics_sampler(inner: &Arc<Self>, interval: Duration) {
+        let inner_weak = Arc::downgrade(inner);
+        inner
+            .executor
+            .spawn(async move {
+                let mut previous: Option<(u32, WorkerResourceUsage, Instant)> = None;
+
+                loop {
+                    Timer::after(interval).await;
+
+                    let inner = match inner_weak.upgrade() {
+                        Some(inner) => inner,
+                        None => break,
+                    };
+                    if inner.closing.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    let (channel, pid) = {
+                        let generation = inner.generation.lock();
+                        (generation.channel.clone(), generation.pid)
+                    };
+
+                    let usage = match channel.request(WorkerGetResourceRequest {}).await {
+                        Ok(usage) => usage,
+                        Err(_) => continue,
+                    };
+                    let now = Instant::now();
+
+                    let cpu_percent = match previous {
+                        Some((previous_pid, previous_usage, previous_at))
+                            if previous_pid == pid =>
+                        {
+                            let elapsed_ms =
+                                now.duration_since(previous_at).as_millis().max(1) as f64;
+                            let cpu_ms = (usage.ru_utime + usage.ru_stime)
+                                .saturating_sub(previous_usage.ru_utime + previous_usage.ru_stime)
+                                as f64;
+                            (cpu_ms / elapsed_ms) * 100.0
+                        }
+                        // First sample for this pid (either the very first tick, or right after
+                        // a respawn): nothing to diff against yet.
+                        _ => 0.0,
+                    };
+
+                    let sample = WorkerMetricsSample {
+                        pid,
+                        cpu_percent,
+                        maxrss: usage.ru_maxrss,
+                        nvcsw: usage.ru_nvcsw,
+                        nivcsw: usage.ru_nivcsw,
+                    };
+
+                    *inner.latest_metrics_sample.lock() = Some(sample);
+                    if let Some(recorder) = &inner.metrics_recorder {
+                        recorder.record(sample);
+                    }
+
+                    previous = Some((pid, usage, now));
+                }
+            })
+            .detach();
+    }
+
+    /// Reacts to the current generation's subprocess having exited, either giving up (see
+    /// [`Self::give_up`]) or respawning per `restart_policy`.
+    async fn handle_exit(
+        self: Arc<Self>,
+        exit_status: ExitStatus,
+        attempt: u32,
+        spawned_at: Instant,
+    ) {
+        if self.closing.load(Ordering::SeqCst) {
+            // Intentional shutdown in progress (`Worker::close`/`Drop`); never respawn in
+            // reaction to it, just record the worker as closed like the no-restart-policy path.
+            self.give_up(exit_status);
+            return;
         }
-        spawn_args.push(format!("--rtcMinPort={}", rtc_ports_range.start()).into());
-        spawn_args.push(format!("--rtcMaxPort={}", rtc_ports_range.end()).into());
-
-        if let Some(dtls_files) = dtls_files {
-            {
-                let mut arg = OsString::new();
-                arg.push("--dtlsCertificateFile=");
-                arg.push(dtls_files.certificate);
-                spawn_args.push(arg);
-            }
-            {
-                let mut arg = OsString::new();
-                arg.push("--dtlsPrivateKeyFile=");
-                arg.push(dtls_files.private_key);
-                spawn_args.push(arg);
+
+        let restart_policy = match self.restart_policy.clone() {
+            Some(restart_policy) => restart_policy,
+            None => {
+                self.give_up(exit_status);
+                return;
             }
+        };
+
+        let attempt = if spawned_at.elapsed() >= restart_policy.stability_window {
+            0
+        } else {
+            attempt
+        };
+
+        if matches!(restart_policy.max_attempts, Some(max_attempts) if attempt >= max_attempts) {
+            warn!(
+                "giving up respawning worker process after {} attempts",
+                attempt
+            );
+            self.give_up(exit_status);
+            return;
         }
 
+        let delay = restart_policy.delay_for_attempt(attempt);
         debug!(
-            "spawning worker process: {} {}",
-            spawn_bin.to_string_lossy(),
-            spawn_args
-                .iter()
-                .map(|arg| arg.to_string_lossy())
-                .collect::<Vec<_>>()
-                .join(" ")
+            "worker process died unexpectedly, respawning in {:?} (attempt {})",
+            delay,
+            attempt + 1,
         );
+        Timer::after(delay).await;
 
-        let mut command = Command::new(spawn_bin);
-        command
-            .args(spawn_args)
-            .stdin(Stdio::null())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .env("MEDIASOUP_VERSION", env!("CARGO_PKG_VERSION"));
+        if self.closing.load(Ordering::SeqCst) {
+            return;
+        }
 
-        let SpawnResult {
-            child,
-            channel,
-            payload_channel,
-        } = utils::spawn_with_worker_channels(Arc::clone(&executor), &mut command)?;
+        match spawn_generation(&self.executor, &self.closed, &self.spawn_settings).await {
+            Ok(new_generation) => {
+                let pid = new_generation.pid;
+                *self.generation.lock() = new_generation;
+                info!("worker process respawned [pid:{}]", pid);
 
-        let pid = child.id();
-        let handlers = Handlers::default();
+                let worker = Worker {
+                    inner: Arc::clone(&self),
+                };
+                self.handlers.restart.call(|callback| {
+                    callback(&worker);
+                });
 
-        let mut inner = Self {
-            channel,
-            payload_channel,
-            child,
-            executor,
-            pid,
-            handlers,
-            app_data,
-            closed: Arc::new(AtomicBool::new(false)),
-            _worker_manager: worker_manager,
+                Self::spawn_exit_watcher(&self, attempt + 1, Instant::now());
+            }
+            Err(error) => {
+                error!("failed to respawn worker process: {}", error);
+                self.give_up(exit_status);
+            }
+        }
+    }
+
+    /// Marks the worker permanently closed and fires `on_dead`/`on_close` exactly once.
+    fn give_up(&self, exit_status: ExitStatus) {
+        if !self.closed.swap(true, Ordering::SeqCst) {
+            self.handlers.dead.call(|callback| {
+                callback(exit_status);
+            });
+            self.handlers.close.call_simple();
+        }
+    }
+
+    /// Reacts to a `WorkerSource::Remote` generation having disconnected, either giving up (see
+    /// [`Self::give_up_remote`]) or reconnecting with backoff, up to the source's
+    /// `reconnect_attempts`.
+    async fn handle_remote_disconnect(self: Arc<Self>, attempt: u32, spawned_at: Instant) {
+        if self.closing.load(Ordering::SeqCst) {
+            self.give_up_remote();
+            return;
+        }
+
+        let reconnect_attempts = match &self.spawn_settings.source {
+            WorkerSource::Remote {
+                reconnect_attempts, ..
+            } => *reconnect_attempts,
+            // Unreachable in practice: only a `Remote` generation is ever watched by
+            // `spawn_remote_watcher`.
+            WorkerSource::Local => return,
         };
 
-        inner.setup_output_forwarding();
+        let attempt = if spawned_at.elapsed() >= RestartPolicy::default().stability_window {
+            0
+        } else {
+            attempt
+        };
 
-        inner.setup_message_handling();
+        if attempt >= reconnect_attempts {
+            warn!(
+                "giving up reconnecting to remote worker after {} attempts",
+                attempt
+            );
+            self.give_up_remote();
+            return;
+        }
 
-        inner.wait_for_worker_process().await?;
+        let delay = RestartPolicy::default().delay_for_attempt(attempt);
+        debug!(
+            "remote worker disconnected, reconnecting in {:?} (attempt {})",
+            delay,
+            attempt + 1,
+        );
+        Timer::after(delay).await;
 
-        let status_fut = inner.child.status();
-        let inner = Arc::new(inner);
-        {
-            let inner_weak = Arc::downgrade(&inner);
-            inner
-                .executor
-                .spawn(async move {
-                    let status = status_fut.await;
-
-                    if let Some(inner) = inner_weak.upgrade() {
-                        if let Ok(exit_status) = status {
-                            warn!("exit status {}", exit_status);
-
-                            if !inner.closed.swap(true, Ordering::SeqCst) {
-                                inner.handlers.dead.call(|callback| {
-                                    callback(exit_status);
-                                });
-                                inner.handlers.close.call_simple();
-                            }
-                        }
-                    }
-                })
-                .detach();
+        if self.closing.load(Ordering::SeqCst) {
+            return;
         }
 
-        Ok(inner)
-    }
+        match spawn_generation(&self.executor, &self.closed, &self.spawn_settings).await {
+            Ok(new_generation) => {
+                let pid = new_generation.pid;
+                *self.generation.lock() = new_generation;
+                info!("remote worker reconnected [pid:{}]", pid);
 
-    fn setup_output_forwarding(&mut self) {
-        let stdout = self.child.stdout.take().unwrap();
-        self.executor
-            .spawn(async move {
-                let mut lines = BufReader::new(stdout).lines();
-                while let Some(Ok(line)) = lines.next().await {
-                    debug!("(stdout) {}", line);
-                }
-            })
-            .detach();
+                let worker = Worker {
+                    inner: Arc::clone(&self),
+                };
+                self.handlers.restart.call(|callback| {
+                    callback(&worker);
+                });
 
-        let stderr = self.child.stderr.take().unwrap();
-        let closed = Arc::clone(&self.closed);
-        self.executor
-            .spawn(async move {
-                let mut lines = BufReader::new(stderr).lines();
-                while let Some(Ok(line)) = lines.next().await {
-                    if !closed.load(Ordering::SeqCst) {
-                        error!("(stderr) {}", line);
-                    }
-                }
-            })
-            .detach();
+                Self::spawn_remote_watcher(&self, attempt + 1, Instant::now());
+            }
+            Err(error) => {
+                error!("failed to reconnect to remote worker: {}", error);
+                self.give_up_remote();
+            }
+        }
     }
 
-    async fn wait_for_worker_process(&mut self) -> io::Result<()> {
-        let status = self.child.status();
-        future::or(
-            async move {
-                let status = status.await?;
-                let error_message = format!(
-                    "worker process exited before being ready, exit status {}, code {:?}",
-                    status,
-                    status.code(),
-                );
-                Err(io::Error::new(io::ErrorKind::NotFound, error_message))
-            },
-            self.wait_for_worker_ready(),
-        )
-        .await
+    /// Marks the worker permanently closed and fires only `on_close`, once reconnection attempts
+    /// for a `WorkerSource::Remote` generation are exhausted. Unlike [`Self::give_up`], `on_dead`
+    /// is never fired here: its callback signature carries a real `ExitStatus`, and there is none
+    /// to report for a network disconnect.
+    fn give_up_remote(&self) {
+        if !self.closed.swap(true, Ordering::SeqCst) {
+            self.handlers.close.call_simple();
+        }
     }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        debug!("drop()");
 
-    async fn wait_for_worker_ready(&mut self) -> io::Result<()> {
-        #[derive(Deserialize)]
-        #[serde(tag = "event", rename_all = "lowercase")]
-        enum Notification {
-            Running,
+        self.closing.store(true, Ordering::SeqCst);
+        let already_closed = self.closed.swap(true, Ordering::SeqCst);
+        let generation = self.generation.get_mut();
+        let pid = generation.pid;
+        // A `Remote` generation has no local process to wait on or signal below; dropping its
+        // `Channel`/`PayloadChannel` (which happens along with the rest of `Generation` right
+        // after this function returns) is the only "disconnect" this crate can perform for it.
+        let still_running = match &mut generation.process {
+            WorkerProcess::Local(child) => matches!(child.try_status(), Ok(None)),
+            WorkerProcess::Remote => false,
+        };
+
+        if still_running {
+            #[cfg(unix)]
+            unsafe {
+                libc::kill(pid as libc::pid_t, libc::SIGTERM);
+            }
+            // `Child::kill()` is an immediate hard kill on Windows (there is no separate
+            // "polite" signal to send), so there is nothing left to escalate below.
+            #[cfg(windows)]
+            if let WorkerProcess::Local(child) = &mut generation.process {
+                let _ = child.kill();
+            }
         }
 
-        let (sender, receiver) = async_oneshot::oneshot();
-        let pid = self.pid;
-        let sender = Mutex::new(Some(sender));
-        let _handler =
-            self.channel
-                .subscribe_to_notifications(self.pid.into(), move |notification| {
-                    let result = match serde_json::from_value(notification.clone()) {
-                        Ok(Notification::Running) => {
-                            debug!("worker process running [pid:{}]", pid);
-                            Ok(())
-                        }
-                        Err(error) => Err(io::Error::new(
-                            io::ErrorKind::Other,
-                            format!(
-                            "unexpected first notification from worker [pid:{}]: {:?}; error = {}",
-                            pid, notification, error
-                        ),
-                        )),
-                    };
-                    let _ = sender
-                        .lock()
-                        .take()
-                        .expect("Receiving more than one worker notification")
-                        .send(result);
-                });
+        // On Unix the worker may ignore SIGTERM, so schedule the same timeout + SIGKILL
+        // escalation `Worker::close` uses instead of leaving a lingering process behind.
+        #[cfg(unix)]
+        if still_running {
+            if let WorkerProcess::Local(child) = &mut generation.process {
+                let status_fut = child.status();
+                let close_timeout = self.close_timeout;
+                self.executor
+                    .spawn(async move {
+                        future::or(
+                            async {
+                                let _ = status_fut.await;
+                            },
+                            async {
+                                Timer::after(close_timeout).await;
+                                unsafe {
+                                    libc::kill(pid as libc::pid_t, libc::SIGKILL);
+                                }
+                            },
+                        )
+                        .await;
+                    })
+                    .detach();
+            }
+        }
 
-        receiver.await.map_err(|_closed| {
-            io::Error::new(io::ErrorKind::Other, "Worker dropped before it is ready")
-        })?
+        if !already_closed {
+            self.handlers.close.call_simple();
+        }
     }
+}
 
-    fn setup_message_handling(&mut self) {
-        let channel_receiver = self.channel.get_internal_message_receiver();
-        let payload_channel_receiver = self.payload_channel.get_internal_message_receiver();
-        let pid = self.pid;
-        let closed = Arc::clone(&self.closed);
-        self.executor
-            .spawn(async move {
-                while let Ok(message) = channel_receiver.recv().await {
-                    match message {
-                        channel::InternalMessage::Debug(text) => debug!("[pid:{}] {}", pid, text),
-                        channel::InternalMessage::Warn(text) => warn!("[pid:{}] {}", pid, text),
-                        channel::InternalMessage::Error(text) => {
-                            if !closed.load(Ordering::SeqCst) {
-                                error!("[pid:{}] {}", pid, text)
-                            }
-                        }
-                        channel::InternalMessage::Dump(text) => eprintln!("{}", text),
-                        channel::InternalMessage::Unexpected(data) => error!(
-                            "worker[pid:{}] unexpected channel data: {}",
-                            pid,
-                            String::from_utf8_lossy(&data)
-                        ),
-                    }
-                }
-            })
-            .detach();
+impl Inner {
+    async fn new(
+        executor: Arc<Executor<'static>>,
+        worker_binary: PathBuf,
+        WorkerSettings {
+            app_data,
+            source,
+            log_level,
+            log_tags,
+            rtc_ports_range,
+            dtls_files,
+            close_timeout,
+            restart_policy,
+            resource_sampling_interval,
+            metrics_recorder,
+            request_policy,
+        }: WorkerSettings,
+        worker_manager: WorkerManager,
+    ) -> Result<Arc<Self>, WorkerError> {
+        debug!("new()");
 
-        self.executor
-            .spawn(async move {
-                while let Ok(message) = payload_channel_receiver.recv().await {
-                    match message {
-                        payload_channel::InternalMessage::UnexpectedData(data) => error!(
-                            "worker[pid:{}] unexpected payload channel data: {}",
-                            pid,
-                            String::from_utf8_lossy(&data)
-                        ),
-                    }
-                }
-            })
-            .detach();
+        let spawn_settings = SpawnSettings {
+            source,
+            worker_binary,
+            log_level,
+            log_tags,
+            rtc_ports_range,
+            dtls_files,
+        };
+
+        let closed = Arc::new(AtomicBool::new(false));
+        let generation = spawn_generation(&executor, &closed, &spawn_settings).await?;
+
+        let inner = Arc::new(Self {
+            generation: Mutex::new(generation),
+            executor,
+            spawn_settings,
+            restart_policy,
+            handlers: Handlers::default(),
+            app_data,
+            closed,
+            closing: Arc::new(AtomicBool::new(false)),
+            close_timeout,
+            metrics_recorder,
+            latest_metrics_sample: Mutex::new(None),
+            request_policy,
+            _worker_manager: worker_manager,
+        });
+
+        Self::spawn_exit_watcher(&inner, 0, Instant::now());
+        if let Some(interval) = resource_sampling_interval {
+            Self::spawn_metrics_sampler(&inner, interval);
+        }
+
+        Ok(inner)
     }
 }
 
@@ -572,7 +1445,7 @@ impl Worker {
         worker_binary: PathBuf,
         worker_settings: WorkerSettings,
         worker_manager: WorkerManager,
-    ) -> io::Result<Self> {
+    ) -> Result<Self, WorkerError> {
         let inner = Inner::new(executor, worker_binary, worker_settings, worker_manager).await?;
 
         Ok(Self { inner })
@@ -580,7 +1453,14 @@ impl Worker {
 
     /// The PID of the worker process.
     pub fn pid(&self) -> u32 {
-        self.inner.pid
+        self.inner.generation.lock().pid
+    }
+
+    /// Capabilities (e.g. SVC, transport types, codecs) the worker subprocess reported
+    /// supporting during its startup handshake, so callers can branch on what it actually
+    /// supports instead of assuming.
+    pub fn capabilities(&self) -> Vec<String> {
+        self.inner.generation.lock().capabilities.clone()
     }
 
     /// Custom application data.
@@ -593,32 +1473,79 @@ impl Worker {
         self.inner.closed.load(Ordering::SeqCst)
     }
 
+    /// Closes the worker, asking the subprocess to shut down and waiting for it to actually
+    /// exit. If it doesn't exit within [`WorkerSettings::close_timeout`], it is killed forcefully.
+    ///
+    /// `on_close` (and, for a [`WorkerSource::Local`] process, `on_dead` with the exit status it
+    /// closed with) handlers are guaranteed to have already run by the time this returns.
+    pub async fn close(&self) {
+        debug!("close()");
+
+        self.inner.close().await;
+    }
+
     /// Dump Worker.
     #[doc(hidden)]
     pub async fn dump(&self) -> Result<WorkerDump, RequestError> {
+        self.dump_with_policy(self.inner.request_policy.clone())
+            .await
+    }
+
+    /// Like [`Self::dump`], but with a [`RequestPolicy`] overriding
+    /// [`WorkerSettings::request_policy`] for this call.
+    #[doc(hidden)]
+    pub async fn dump_with_policy(
+        &self,
+        policy: RequestPolicy,
+    ) -> Result<WorkerDump, RequestError> {
         debug!("dump()");
 
-        self.inner.channel.request(WorkerDumpRequest {}).await
+        let channel = self.inner.generation.lock().channel.clone();
+        request_with_policy(&channel, &policy, true, WorkerDumpRequest {}).await
     }
 
     /// Provides resource usage of the mediasoup-worker subprocess.
     pub async fn get_resource_usage(&self) -> Result<WorkerResourceUsage, RequestError> {
+        self.get_resource_usage_with_policy(self.inner.request_policy.clone())
+            .await
+    }
+
+    /// Like [`Self::get_resource_usage`], but with a [`RequestPolicy`] overriding
+    /// [`WorkerSettings::request_policy`] for this call.
+    pub async fn get_resource_usage_with_policy(
+        &self,
+        policy: RequestPolicy,
+    ) -> Result<WorkerResourceUsage, RequestError> {
         debug!("get_resource_usage()");
 
-        self.inner
-            .channel
-            .request(WorkerGetResourceRequest {})
-            .await
+        let channel = self.inner.generation.lock().channel.clone();
+        request_with_policy(&channel, &policy, true, WorkerGetResourceRequest {}).await
+    }
+
+    /// Returns the latest sample taken by the periodic sampler started via
+    /// [`WorkerSettings::resource_sampling_interval`], or `None` if sampling is disabled or the
+    /// first sample hasn't been taken yet.
+    pub fn metrics_snapshot(&self) -> Option<WorkerMetricsSample> {
+        *self.inner.latest_metrics_sample.lock()
     }
 
     /// Updates the worker settings in runtime. Just a subset of the worker settings can be updated.
     pub async fn update_settings(&self, data: WorkerUpdateSettings) -> Result<(), RequestError> {
+        self.update_settings_with_policy(data, self.inner.request_policy.clone())
+            .await
+    }
+
+    /// Like [`Self::update_settings`], but with a [`RequestPolicy`] overriding
+    /// [`WorkerSettings::request_policy`] for this call.
+    pub async fn update_settings_with_policy(
+        &self,
+        data: WorkerUpdateSettings,
+        policy: RequestPolicy,
+    ) -> Result<(), RequestError> {
         debug!("update_settings()");
 
-        self.inner
-            .channel
-            .request(WorkerUpdateSettingsRequest { data })
-            .await
+        let channel = self.inner.generation.lock().channel.clone();
+        request_with_policy(&channel, &policy, true, WorkerUpdateSettingsRequest { data }).await
     }
 
     /// Create a Router.
@@ -627,6 +1554,19 @@ impl Worker {
     pub async fn create_router(
         &self,
         router_options: RouterOptions,
+    ) -> Result<Router, CreateRouterError> {
+        self.create_router_with_policy(router_options, self.inner.request_policy.clone())
+            .await
+    }
+
+    /// Like [`Self::create_router`], but with a [`RequestPolicy`] overriding
+    /// [`WorkerSettings::request_policy`] for this call. `create_router` is not idempotent (each
+    /// successful request creates a distinct router), so `policy.retry` is ignored here: the
+    /// request is always attempted exactly once, racing only against `policy.timeout`.
+    pub async fn create_router_with_policy(
+        &self,
+        router_options: RouterOptions,
+        policy: RequestPolicy,
     ) -> Result<Router, CreateRouterError> {
         debug!("create_router()");
 
@@ -641,19 +1581,22 @@ impl Worker {
         let router_id = RouterId::new();
         let internal = RouterInternal { router_id };
 
-        let _buffer_guard = self.inner.channel.buffer_messages_for(router_id.into());
+        let (channel, payload_channel) = {
+            let generation = self.inner.generation.lock();
+            (generation.channel.clone(), generation.payload_channel.clone())
+        };
 
-        self.inner
-            .channel
-            .request(WorkerCreateRouterRequest { internal })
+        let _buffer_guard = channel.buffer_messages_for(router_id.into());
+
+        request_with_policy(&channel, &policy, false, WorkerCreateRouterRequest { internal })
             .await
             .map_err(CreateRouterError::Request)?;
 
         let router = Router::new(
             router_id,
             Arc::clone(&self.inner.executor),
-            self.inner.channel.clone(),
-            self.inner.payload_channel.clone(),
+            channel,
+            payload_channel,
             rtp_capabilities,
             app_data,
             self.clone(),
@@ -671,11 +1614,21 @@ impl Worker {
         self.inner.handlers.new_router.add(Box::new(callback))
     }
 
-    /// Callback is called when the worker process unexpectedly dies.
+    /// Callback is called when the worker process exits and is not going to be respawned: it died
+    /// unexpectedly and either there is no `restart_policy` or it ran out of attempts, or it exited
+    /// during [`Worker::close`]. Never called for a [`WorkerSource::Remote`] worker.
     pub fn on_dead<F: FnOnce(ExitStatus) + Send + Sync + 'static>(&self, callback: F) -> HandlerId {
         self.inner.handlers.dead.add(Box::new(callback))
     }
 
+    /// Callback is called when the worker subprocess is successfully respawned after an
+    /// unexpected death (only possible when [`WorkerSettings::restart_policy`] is set). Existing
+    /// [`Router`]s still point at the dead process, so this is the place to recreate them against
+    /// `worker`.
+    pub fn on_restart<F: Fn(&Worker) + Send + Sync + 'static>(&self, callback: F) -> HandlerId {
+        self.inner.handlers.restart.add(Box::new(callback))
+    }
+
     /// Callback is called when the worker is closed for whatever reason.
     ///
     /// NOTE: Callback will be called in place if worker is already closed.
@@ -0,0 +1,78 @@
+//! Request-id correlation for matching a worker channel's incoming responses back to whichever
+//! of its concurrent in-flight requests is awaiting them.
+//!
+//! This only provides the bookkeeping primitive: a monotonically increasing id assigned per
+//! request, a pending-request map keyed by that id, and a timeout that removes the pending slot
+//! so a response arriving after the fact can never resolve into a stale one. Wiring this into an
+//! actual reader task that demuxes the worker's raw incoming messages by id, and exposing the
+//! channel's underlying file descriptor for an external event loop, both need [`Channel`]'s
+//! internals, which aren't part of this crate snapshot (`worker/channel.rs` is only a `mod
+//! channel;` declaration with no implementation file backing it).
+//!
+//! [`Channel`]: super::Channel
+
+use crate::worker::RequestError;
+use async_io::Timer;
+use async_oneshot::Receiver;
+use futures_lite::future;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+/// Tracks requests awaiting a response, keyed by a monotonically increasing id assigned by
+/// [`Self::register`]. Whatever demuxes incoming worker messages by id is expected to call
+/// [`Self::complete`] with the raw response bytes for a given id; [`Self::wait_for_response`]
+/// races that against a timeout and removes the pending entry either way.
+#[derive(Default)]
+pub(crate) struct PendingRequests {
+    next_id: AtomicU32,
+    pending: Mutex<HashMap<u32, async_oneshot::Sender<Vec<u8>>>>,
+}
+
+impl PendingRequests {
+    /// Allocates the next request id and registers a pending slot for it, returning the id (to
+    /// be serialized into the outgoing message envelope via [`Codec::encode`]) and the receiving
+    /// half of the oneshot that a later [`Self::complete`] call completes.
+    ///
+    /// [`Codec::encode`]: crate::messages::Codec::encode
+    pub(crate) fn register(&self) -> (u32, Receiver<Vec<u8>>) {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = async_oneshot::oneshot();
+        self.pending.lock().insert(id, sender);
+        (id, receiver)
+    }
+
+    /// Completes the pending request for `id` with its raw response bytes, if it's still
+    /// waiting (it may have already timed out and been removed by [`Self::remove`]).
+    pub(crate) fn complete(&self, id: u32, bytes: Vec<u8>) {
+        if let Some(mut sender) = self.pending.lock().remove(&id) {
+            let _ = sender.send(bytes);
+        }
+    }
+
+    /// Removes the pending slot for `id` without completing it.
+    pub(crate) fn remove(&self, id: u32) {
+        self.pending.lock().remove(&id);
+    }
+
+    /// Waits for the response registered under `id`, or `RequestError::TimedOut` if `timeout`
+    /// elapses first — removing the pending slot either way, so a response that arrives after a
+    /// timeout has nowhere left to land.
+    pub(crate) async fn wait_for_response(
+        &self,
+        id: u32,
+        receiver: Receiver<Vec<u8>>,
+        timeout: Duration,
+    ) -> Result<Vec<u8>, RequestError> {
+        future::or(
+            async { receiver.await.map_err(|_closed| RequestError::ChannelClosed) },
+            async {
+                Timer::after(timeout).await;
+                self.remove(id);
+                Err(RequestError::TimedOut)
+            },
+        )
+        .await
+    }
+}
@@ -1,113 +1,190 @@
 // Contents of this module is inspired by https://github.com/Srinivasa314/alcro/tree/master/src/chrome
+//
+// The worker's control/payload channels are transported differently per platform: Unix inherits
+// a pair of anonymous pipes as extra file descriptors, Windows talks to the worker over loopback
+// TCP sockets instead (mediasoup-worker must be built with the corresponding channel backend for
+// the platform it's running on). Which one gets compiled in is selected below via `cfg`, but the
+// public `spawn_with_worker_channels` entry point and its `SpawnResult` are the same either way.
 use crate::worker::{Channel, PayloadChannel};
 use async_executor::Executor;
-use async_fs::File;
-use async_oneshot::Receiver;
-use once_cell::sync::Lazy;
-use parking_lot::Mutex;
-use std::ffi::CString;
-use std::mem;
-use std::os::raw::{c_char, c_int};
-use std::os::unix::io::FromRawFd;
+use async_process::{Child, Command};
+use std::io;
 use std::sync::Arc;
-use thiserror::Error;
-
-#[derive(Debug, Copy, Clone, Error)]
-pub enum ExitError {
-    /// Generic error.
-    #[error("Worker exited with generic error")]
-    Generic,
-    /// Settings error.
-    #[error("Worker exited with settings error")]
-    Settings,
-    /// Unknown error.
-    #[error("Worker exited with unknown error and status code {status_code}")]
-    Unknown { status_code: i32 },
-    /// Unexpected error.
-    #[error("Worker exited unexpectedly")]
-    Unexpected,
+
+pub(super) struct SpawnResult {
+    pub(super) child: Child,
+    pub(super) channel: Channel,
+    pub(super) payload_channel: PayloadChannel,
+}
+
+#[cfg(unix)]
+pub(super) fn spawn_with_worker_channels(
+    executor: Arc<Executor<'static>>,
+    command: &mut Command,
+) -> io::Result<SpawnResult> {
+    unix::spawn_with_worker_channels(executor, command)
+}
+
+#[cfg(windows)]
+pub(super) fn spawn_with_worker_channels(
+    executor: Arc<Executor<'static>>,
+    command: &mut Command,
+) -> io::Result<SpawnResult> {
+    windows::spawn_with_worker_channels(executor, command)
 }
 
-fn pipe() -> [c_int; 2] {
-    unsafe {
-        let mut fds = mem::MaybeUninit::<[c_int; 2]>::uninit();
+#[cfg(unix)]
+mod unix {
+    use super::SpawnResult;
+    use crate::worker::{Channel, PayloadChannel};
+    use async_executor::Executor;
+    use async_fs::File;
+    use async_process::{unix::CommandExt, Command};
+    use std::mem;
+    use std::os::raw::c_int;
+    use std::os::unix::io::FromRawFd;
+    use std::sync::Arc;
+    use std::io;
+
+    // mediasoup-worker reads its control/payload channels from these fixed, inherited file
+    // descriptor numbers rather than from stdin/stdout (those are left free for logging).
+    const CONSUMER_CHANNEL_FD: c_int = 3;
+    const PRODUCER_CHANNEL_FD: c_int = 4;
+    const CONSUMER_PAYLOAD_CHANNEL_FD: c_int = 5;
+    const PRODUCER_PAYLOAD_CHANNEL_FD: c_int = 6;
+
+    fn pipe() -> io::Result<[c_int; 2]> {
+        unsafe {
+            let mut fds = mem::MaybeUninit::<[c_int; 2]>::uninit();
+
+            if libc::pipe(fds.as_mut_ptr() as *mut c_int) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            Ok(fds.assume_init())
+        }
+    }
+
+    pub(super) fn spawn_with_worker_channels(
+        executor: Arc<Executor<'static>>,
+        command: &mut Command,
+    ) -> io::Result<SpawnResult> {
+        let [producer_fd_read, producer_fd_write] = pipe()?;
+        let [consumer_fd_read, consumer_fd_write] = pipe()?;
+        let [producer_payload_fd_read, producer_payload_fd_write] = pipe()?;
+        let [consumer_payload_fd_read, consumer_payload_fd_write] = pipe()?;
+
+        // Hand the "read" ends of the producer pipes and the "write" ends of the consumer pipes
+        // to the child under fixed fd numbers; we keep the other ends for ourselves below.
+        command.fd_mappings(vec![
+            async_process::unix::FdMapping {
+                parent_fd: producer_fd_read,
+                child_fd: CONSUMER_CHANNEL_FD,
+            },
+            async_process::unix::FdMapping {
+                parent_fd: consumer_fd_write,
+                child_fd: PRODUCER_CHANNEL_FD,
+            },
+            async_process::unix::FdMapping {
+                parent_fd: producer_payload_fd_read,
+                child_fd: CONSUMER_PAYLOAD_CHANNEL_FD,
+            },
+            async_process::unix::FdMapping {
+                parent_fd: consumer_payload_fd_write,
+                child_fd: PRODUCER_PAYLOAD_CHANNEL_FD,
+            },
+        ]);
+
+        let child = command.spawn()?;
 
-        if libc::pipe(fds.as_mut_ptr() as *mut c_int) != 0 {
-            panic!(
-                "libc::pipe() failed with code {}",
-                *libc::__errno_location()
-            );
+        // Close our copies of the ends that now belong to the child (the pipe's other end stays
+        // open via the fd mapping above until the child exits).
+        let child_owned_fds = [
+            producer_fd_read,
+            consumer_fd_write,
+            producer_payload_fd_read,
+            consumer_payload_fd_write,
+        ];
+        for fd in child_owned_fds {
+            unsafe {
+                libc::close(fd);
+            }
         }
 
-        fds.assume_init()
+        let producer_file = unsafe { File::from_raw_fd(producer_fd_write) };
+        let consumer_file = unsafe { File::from_raw_fd(consumer_fd_read) };
+        let producer_payload_file = unsafe { File::from_raw_fd(producer_payload_fd_write) };
+        let consumer_payload_file = unsafe { File::from_raw_fd(consumer_payload_fd_read) };
+
+        Ok(SpawnResult {
+            child,
+            channel: Channel::new(Arc::clone(&executor), consumer_file, producer_file),
+            payload_channel: PayloadChannel::new(
+                executor,
+                consumer_payload_file,
+                producer_payload_file,
+            ),
+        })
     }
 }
 
-static SPAWNING: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+#[cfg(windows)]
+mod windows {
+    use super::SpawnResult;
+    use crate::worker::{Channel, PayloadChannel};
+    use async_executor::Executor;
+    use async_net::{TcpListener, TcpStream};
+    use async_process::Command;
+    use futures_lite::future;
+    use std::io;
+    use std::sync::Arc;
 
-pub(super) struct WorkerRunResult {
-    pub(super) channel: Channel,
-    pub(super) payload_channel: PayloadChannel,
-    pub(super) status_receiver: Receiver<Result<(), ExitError>>,
-}
+    // Real named pipes would avoid the loopback-only exposure of a TCP socket, but async-net
+    // doesn't wrap the Win32 named pipe API, so we use a pair of localhost sockets instead: one
+    // for the control channel and one for the payload channel, each carrying both directions.
+    async fn bind_pair() -> io::Result<(TcpListener, TcpListener)> {
+        Ok((
+            TcpListener::bind("127.0.0.1:0").await?,
+            TcpListener::bind("127.0.0.1:0").await?,
+        ))
+    }
 
-pub(super) fn run_worker_with_channels(
-    executor: Arc<Executor<'static>>,
-    args: Vec<String>,
-) -> WorkerRunResult {
-    // Take a lock to make sure we don't spawn workers from multiple threads concurrently, this
-    // causes racy issues
-    let _lock = SPAWNING.lock();
-    let [producer_fd_read, producer_fd_write] = pipe();
-    let [consumer_fd_read, consumer_fd_write] = pipe();
-    let [producer_payload_fd_read, producer_payload_fd_write] = pipe();
-    let [consumer_payload_fd_read, consumer_payload_fd_write] = pipe();
-    let (status_sender, status_receiver) = async_oneshot::oneshot();
-
-    std::thread::spawn(move || {
-        let argc = args.len() as c_int;
-        let args_cstring = args
-            .into_iter()
-            .map(|s| -> CString { CString::new(s).unwrap() })
-            .collect::<Vec<_>>();
-        let argv = args_cstring
-            .iter()
-            .map(|arg| arg.as_ptr() as *const c_char)
-            .collect::<Vec<_>>();
-        let version = CString::new(env!("CARGO_PKG_VERSION")).unwrap();
-        let status_code = unsafe {
-            mediasoup_sys::run_worker(
-                argc,
-                argv.as_ptr(),
-                version.as_ptr(),
-                false,
-                producer_fd_read,
-                consumer_fd_write,
-                producer_payload_fd_read,
-                consumer_payload_fd_write,
-            )
-        };
-
-        let _ = status_sender.send(match status_code {
-            0 => Ok(()),
-            1 => Err(ExitError::Generic),
-            42 => Err(ExitError::Settings),
-            status_code => Err(ExitError::Unknown { status_code }),
-        });
-    });
-
-    let producer_file = unsafe { File::from_raw_fd(producer_fd_write) };
-    let consumer_file = unsafe { File::from_raw_fd(consumer_fd_read) };
-    let producer_payload_file = unsafe { File::from_raw_fd(producer_payload_fd_write) };
-    let consumer_payload_file = unsafe { File::from_raw_fd(consumer_payload_fd_read) };
-
-    WorkerRunResult {
-        channel: Channel::new(Arc::clone(&executor), consumer_file, producer_file),
-        payload_channel: PayloadChannel::new(
-            executor,
-            consumer_payload_file,
-            producer_payload_file,
-        ),
-        status_receiver,
+    pub(super) fn spawn_with_worker_channels(
+        executor: Arc<Executor<'static>>,
+        command: &mut Command,
+    ) -> io::Result<SpawnResult> {
+        future::block_on(executor.run(async {
+            let (channel_listener, payload_channel_listener) = bind_pair().await?;
+
+            command
+                .arg(format!(
+                    "--channelAddress={}",
+                    channel_listener.local_addr()?
+                ))
+                .arg(format!(
+                    "--payloadChannelAddress={}",
+                    payload_channel_listener.local_addr()?
+                ));
+
+            let child = command.spawn()?;
+
+            let (channel_stream, _): (TcpStream, _) = channel_listener.accept().await?;
+            let (payload_channel_stream, _): (TcpStream, _) =
+                payload_channel_listener.accept().await?;
+
+            Ok(SpawnResult {
+                child,
+                channel: Channel::new(
+                    Arc::clone(&executor),
+                    channel_stream.clone(),
+                    channel_stream,
+                ),
+                payload_channel: PayloadChannel::new(
+                    executor,
+                    payload_channel_stream.clone(),
+                    payload_channel_stream,
+                ),
+            })
+        }))
     }
 }